@@ -0,0 +1,79 @@
+extern crate zelf;
+
+use clap::Parser;
+use std::borrow::Cow;
+use zelf::context::Context;
+use zelf::dwarf::{DebugLine, Location};
+use zelf::elf::{Elf, Variant};
+use zelf::section::Sections;
+use zelf::symbolizer::Symbolizer;
+
+#[derive(Parser, Debug)]
+#[clap(author, version, about)]
+pub struct Args {
+    #[clap(short = 'e', long = "exe", default_value = "a.out")]
+    file: String,
+    /// Show function names
+    #[clap(short = 'f', long)]
+    functions: bool,
+    addresses: Vec<String>,
+}
+
+fn main() {
+    let args = Args::parse();
+    let bytes = std::fs::read(&args.file).expect("Cannot read the file.");
+    match Elf::parse(&bytes).expect("Cannot parse the file.") {
+        Elf::Little32(elf) => run(&args, elf),
+        Elf::Little64(elf) => run(&args, elf),
+        Elf::Big32(elf) => run(&args, elf),
+        Elf::Big64(elf) => run(&args, elf),
+    }
+}
+
+fn run<T: Context>(args: &Args, elf: Variant<'_, T>) {
+    let sections = Sections::parse(elf).unwrap().expect("No section headers.");
+    let section = |x: &[u8]| match sections.decompressed_by_name(x) {
+        Some(x) => x.expect("Cannot decompress the section."),
+        None => Cow::Borrowed(&[][..]),
+    };
+    let line = section(b".debug_line");
+    let line_str = section(b".debug_line_str");
+    let str = section(b".debug_str");
+    let debug_line = DebugLine::<T>::new(&line, &line_str, &str);
+    let symbolizer = args
+        .functions
+        .then(|| Symbolizer::parse(elf).ok())
+        .flatten();
+    for address in args.addresses.iter() {
+        let address = address.trim_start_matches("0x");
+        let address = u64::from_str_radix(address, 16).expect("Bad address.");
+        if args.functions {
+            let name = symbolizer.as_ref().and_then(|x| x.lookup(address));
+            match name {
+                Some((name, _)) => println!("{}", String::from_utf8_lossy(name)),
+                None => println!("??"),
+            }
+        }
+        match debug_line.find(address) {
+            Ok(Some(location)) => println!("{}", display(&location)),
+            Ok(None) => println!("??:0"),
+            Err(e) => println!("??:0 ({:?})", e),
+        }
+    }
+}
+
+fn display(location: &Location) -> String {
+    let file = match location.file {
+        Some(file) => {
+            let name = String::from_utf8_lossy(file.name);
+            match file.directory {
+                Some(directory) if !name.starts_with('/') => {
+                    format!("{}/{}", String::from_utf8_lossy(directory), name)
+                }
+                _ => name.into_owned(),
+            }
+        }
+        None => String::from("??"),
+    };
+    format!("{}:{}", file, location.line)
+}
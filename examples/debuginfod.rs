@@ -0,0 +1,28 @@
+extern crate zelf;
+
+use clap::Parser;
+use zelf::debuginfod_server::DebuginfodIndex;
+
+#[derive(Parser, Debug)]
+#[clap(author, version, about)]
+pub struct Args {
+    #[clap(long, default_value = "127.0.0.1:8002")]
+    listen: String,
+    #[clap(long, default_value_t = 16)]
+    workers: usize,
+    directories: Vec<String>,
+}
+
+fn main() {
+    let args = Args::parse();
+    let mut index = DebuginfodIndex::new();
+    for directory in args.directories.iter() {
+        let count = index
+            .scan(directory.as_ref())
+            .expect("Cannot scan the directory.");
+        println!("Indexed {} files in {}.", count, directory);
+    }
+    let listener = std::net::TcpListener::bind(&args.listen).expect("Cannot listen.");
+    println!("Serving {} build IDs on {}.", index.len(), args.listen);
+    index.serve(&listener, args.workers).unwrap();
+}
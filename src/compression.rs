@@ -1,5 +1,7 @@
 use crate::context::*;
 use crate::utils::*;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[derive(Debug, Clone)]
 pub enum ParseCompressionError {
@@ -8,6 +10,16 @@ pub enum ParseCompressionError {
     BrokenContent,
 }
 
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub enum DecompressError {
+    FromCompression(ParseCompressionError),
+    BadPropertyType,
+    BadPropertySize,
+    BrokenZlib,
+    BrokenZstd,
+}
+
 /// Compressed section.
 #[derive(Debug, Clone, Copy)]
 pub struct Compression<'a, T: Context> {
@@ -31,12 +43,98 @@ impl<'a, T: Context> Compression<'a, T> {
     pub fn content(&self) -> &'a [u8] {
         self.content
     }
+    /// Decompresses the content, checking its size against "ch_size".
+    #[cfg(feature = "alloc")]
+    pub fn decompress(&self) -> Result<Vec<u8>, DecompressError> {
+        use DecompressError::*;
+        let size: u64 = self.header.size().into();
+        let size = usize::try_from(size).map_err(|_| BadPropertySize)?;
+        let decompressed = match self.header.typa() {
+            CompressionType::Zlib => inflate(self.content, size)?,
+            CompressionType::Zstd => {
+                use ruzstd::decoding::{BlockDecodingStrategy, FrameDecoder};
+                // "ch_size" is untrusted, so the output grows as frames are decoded
+                let mut decompressed = Vec::new();
+                let mut decoder = FrameDecoder::new();
+                let mut input = self.content;
+                while !input.is_empty() {
+                    decoder.init(&mut input).map_err(|_| BrokenZstd)?;
+                    while !decoder.is_finished() {
+                        decoder
+                            .decode_blocks(&mut input, BlockDecodingStrategy::UptoBytes(1 << 20))
+                            .map_err(|_| BrokenZstd)?;
+                        decompressed.extend(decoder.collect().unwrap_or_default());
+                        if decompressed.len() > size {
+                            return Err(BadPropertySize);
+                        }
+                    }
+                }
+                decompressed
+            }
+            _ => return Err(BadPropertyType),
+        };
+        if decompressed.len() != size {
+            return Err(BadPropertySize);
+        }
+        Ok(decompressed)
+    }
+}
+
+/// Compressed section in the GNU style, which is named ".zdebug_*" instead of ".debug_*".
+///
+/// The content starts with "ZLIB" and the decompressed size in big endian, instead of a compression header.
+#[derive(Debug, Clone, Copy)]
+pub struct GnuCompression<'a> {
+    size: u64,
+    content: &'a [u8],
+}
+
+impl<'a> GnuCompression<'a> {
+    pub fn parse(content: &'a [u8]) -> Result<Self, ParseCompressionError> {
+        use ParseCompressionError::*;
+        let magic: &[u8; 4] = read(content, 0).ok_or(BrokenHeader)?;
+        let size: &PropU64 = read(content, 4).ok_or(BrokenHeader)?;
+        if magic != b"ZLIB" {
+            return Err(BadPropertyType);
+        }
+        Ok(Self {
+            size: u64::from_be_bytes(*size),
+            content: &content[12..],
+        })
+    }
+    /// Decompressed size.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+    pub fn content(&self) -> &'a [u8] {
+        self.content
+    }
+    /// Decompresses the content, checking its size against the size in the header.
+    #[cfg(feature = "alloc")]
+    pub fn decompress(&self) -> Result<Vec<u8>, DecompressError> {
+        let size = usize::try_from(self.size).map_err(|_| DecompressError::BadPropertySize)?;
+        let decompressed = inflate(self.content, size)?;
+        if decompressed.len() != size {
+            return Err(DecompressError::BadPropertySize);
+        }
+        Ok(decompressed)
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn inflate(content: &[u8], size: usize) -> Result<Vec<u8>, DecompressError> {
+    use miniz_oxide::inflate::{decompress_to_vec_zlib_with_limit, TINFLStatus};
+    decompress_to_vec_zlib_with_limit(content, size).map_err(|x| match x.status {
+        TINFLStatus::HasMoreOutput => DecompressError::BadPropertySize,
+        _ => DecompressError::BrokenZlib,
+    })
 }
 
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct CompressionHeader<T: Context> {
     pub typa: PropU32,
+    pub reserved: T::PropU32If64,
     pub size: T::PropUsize,
     pub addralign: T::PropUsize,
 }
@@ -58,9 +156,10 @@ impl<T: Context> CompressionHeader<T> {
 
 unsafe impl<T: Context> Pod for CompressionHeader<T> {}
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CompressionType {
     Zlib,
+    Zstd,
     OsSpecific(u32),
     ProcessorSpecific(u32),
 }
@@ -72,6 +171,7 @@ impl TryFrom<u32> for CompressionType {
         use CompressionType::*;
         match value {
             1 => Ok(Zlib),
+            2 => Ok(Zstd),
             x @ 0x60000000..=0x6fffffff => Ok(OsSpecific(x)),
             x @ 0x70000000..=0x7fffffff => Ok(ProcessorSpecific(x)),
             _ => Err(()),
@@ -84,6 +184,7 @@ impl From<CompressionType> for u32 {
         use CompressionType::*;
         match value {
             Zlib => 1,
+            Zstd => 2,
             OsSpecific(x) => x,
             ProcessorSpecific(x) => x,
         }
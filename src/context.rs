@@ -26,7 +26,7 @@ where
 
     type SectionFlags: Copy + Debug + From<Self::Integer> + Into<Self::Integer>;
 
-    type DynamicTag: Copy + Debug + From<Self::Integer> + Into<Self::Integer>;
+    type DynamicTag: Copy + Debug + From<Self::Integer> + Into<Self::Integer> + Into<DynamicTag64>;
 
     type DynamicFlags: Copy + Debug + From<Self::Integer> + Into<Self::Integer>;
 }
@@ -0,0 +1,164 @@
+use crate::context::*;
+use crate::core_note::{CoreNote, FileNote, ParseCoreNoteError};
+use crate::elf::{ElfType, Variant};
+use crate::gnu_note::BuildId;
+use crate::note::{Notes, ParseNotesError};
+use crate::program::{ParseProgramError, ParseProgramsError, Program, ProgramType, Programs};
+
+#[derive(Debug, Clone)]
+pub enum ParseCoreImageError {
+    BadPropertyType,
+    MissingPrograms,
+    FromPrograms(ParseProgramsError),
+    FromProgram(ParseProgramError),
+    FromNotes(ParseNotesError),
+    FromCoreNote(ParseCoreNoteError),
+}
+
+#[derive(Debug, Clone)]
+pub enum ReadCoreImageError {
+    /// The address is not mapped by any load program.
+    Unmapped(u64),
+    /// The address is mapped, but its content is not present in the core dump.
+    Missing(u64),
+}
+
+/// Address space of a core dump, given by its load programs.
+#[derive(Debug, Clone, Copy)]
+pub struct CoreImage<'a, T: Context> {
+    elf: Variant<'a, T>,
+    programs: Programs<'a, T>,
+    file: Option<FileNote<'a, T>>,
+}
+
+impl<'a, T: Context> CoreImage<'a, T> {
+    pub fn parse(elf: Variant<'a, T>) -> Result<Self, ParseCoreImageError> {
+        use ParseCoreImageError::*;
+        if elf.header().checked_type() != Some(ElfType::Core) {
+            return Err(BadPropertyType);
+        }
+        let programs = Programs::parse(elf)
+            .map_err(FromPrograms)?
+            .ok_or(MissingPrograms)?;
+        let mut file = None;
+        for (_, program) in programs.iter() {
+            let program = program.map_err(FromProgram)?;
+            if program.header().typa() != ProgramType::Note || file.is_some() {
+                continue;
+            }
+            let notes = Notes::<T>::parse(program.content(), program.header().align())
+                .map_err(FromNotes)?;
+            for note in notes.iter() {
+                if let Some(CoreNote::File(x)) = CoreNote::parse(note).map_err(FromCoreNote)? {
+                    file = Some(x);
+                    break;
+                }
+            }
+        }
+        Ok(Self {
+            elf,
+            programs,
+            file,
+        })
+    }
+    pub fn elf(&self) -> Variant<'a, T> {
+        self.elf
+    }
+    pub fn programs(&self) -> Programs<'a, T> {
+        self.programs
+    }
+    /// Mapped files, given by the "NT_FILE" note.
+    pub fn file(&self) -> Option<FileNote<'a, T>> {
+        self.file
+    }
+    /// Iterates over load programs.
+    pub fn loads(&self) -> impl Iterator<Item = Program<'a, T>> {
+        self.programs
+            .iter()
+            .map(|(_, x)| x.unwrap())
+            .filter(|x| x.header().typa() == ProgramType::Load)
+    }
+    /// Returns the memory from the address to the end of its load program.
+    pub fn get(&self, vaddr: u64) -> Option<Memory<'a>> {
+        self.loads().find_map(|x| {
+            let start: u64 = x.header().vaddr().into();
+            let size: u64 = x.header().memsz().into();
+            let offset = vaddr.checked_sub(start).filter(|&x| x < size)?;
+            match usize::try_from(offset) {
+                Ok(offset) if offset < x.content().len() => {
+                    Some(Memory::Present(&x.content()[offset..]))
+                }
+                _ => Some(Memory::Missing(size - offset)),
+            }
+        })
+    }
+    /// Reads memory at the address, which may cross load programs.
+    ///
+    /// Bytes beyond "p_filesz" of a load program are reported as missing, instead of zeros.
+    pub fn read(&self, vaddr: u64, buffer: &mut [u8]) -> Result<(), ReadCoreImageError> {
+        use ReadCoreImageError::*;
+        let mut done = 0usize;
+        while done < buffer.len() {
+            let address = vaddr.wrapping_add(done as u64);
+            match self.get(address) {
+                Some(Memory::Present(x)) => {
+                    let n = core::cmp::min(x.len(), buffer.len() - done);
+                    buffer[done..done + n].copy_from_slice(&x[..n]);
+                    done += n;
+                }
+                Some(Memory::Missing(_)) => return Err(Missing(address)),
+                None => return Err(Unmapped(address)),
+            }
+        }
+        Ok(())
+    }
+    /// Iterates over loaded modules, given by the "NT_FILE" note.
+    ///
+    /// A module starts at a mapping of file offset zero, and includes the following mappings of the same file.
+    pub fn modules(&self) -> impl Iterator<Item = Module<'a>> {
+        let image = *self;
+        let mut files = self.file.into_iter().flat_map(|x| x.iter()).peekable();
+        core::iter::from_fn(move || loop {
+            let file = files.next()?;
+            if file.offset() != 0 {
+                continue;
+            }
+            let mut end = file.end();
+            while let Some(next) = files.next_if(|x| x.name() == file.name() && x.offset() != 0) {
+                end = core::cmp::max(end, next.end());
+            }
+            return Some(Module {
+                name: file.name(),
+                base: file.start(),
+                end,
+                build_id: image.build_id(file.start()),
+            });
+        })
+    }
+    /// Finds the build ID in the ELF headers at the address, if they are present in the core dump.
+    pub fn build_id(&self, base: u64) -> Option<BuildId<'a>> {
+        let memory = match self.get(base)? {
+            Memory::Present(x) => x,
+            Memory::Missing(_) => return None,
+        };
+        BuildId::find(Variant::<T>::checked_parse(memory)?)
+    }
+}
+
+/// Memory in a load program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Memory<'a> {
+    /// Bytes present in the core dump.
+    Present(&'a [u8]),
+    /// Number of bytes not present in the core dump.
+    Missing(u64),
+}
+
+/// Loaded module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Module<'a> {
+    pub name: &'a [u8],
+    pub base: u64,
+    pub end: u64,
+    pub build_id: Option<BuildId<'a>>,
+}
@@ -0,0 +1,631 @@
+use crate::array::ArrayEntry;
+use crate::context::PropU16;
+use crate::context::*;
+use crate::elf::*;
+use crate::note::Note;
+use crate::utils::{read, read_n, read_s, terminate, Pod};
+use crate::Class;
+
+/// Process status, including registers.
+pub const NOTE_CORE_PRSTATUS: u32 = 1;
+/// Floating point registers.
+pub const NOTE_CORE_FPREGSET: u32 = 2;
+/// Process information.
+pub const NOTE_CORE_PRPSINFO: u32 = 3;
+/// Task struct.
+pub const NOTE_CORE_TASKSTRUCT: u32 = 4;
+/// Auxiliary vector.
+pub const NOTE_CORE_AUXV: u32 = 6;
+/// Signal information.
+pub const NOTE_CORE_SIGINFO: u32 = 0x53494749;
+/// Mapped files.
+pub const NOTE_CORE_FILE: u32 = 0x46494c45;
+
+/// End of the auxiliary vector.
+pub const AUXV_NULL: u64 = 0;
+/// Address of program headers.
+pub const AUXV_PHDR: u64 = 3;
+/// Size of a program header.
+pub const AUXV_PHENT: u64 = 4;
+/// Number of program headers.
+pub const AUXV_PHNUM: u64 = 5;
+/// Page size.
+pub const AUXV_PAGESZ: u64 = 6;
+/// Base address of the interpreter.
+pub const AUXV_BASE: u64 = 7;
+/// Entry point of the program.
+pub const AUXV_ENTRY: u64 = 9;
+/// Real user ID.
+pub const AUXV_UID: u64 = 11;
+/// Effective user ID.
+pub const AUXV_EUID: u64 = 12;
+/// Real group ID.
+pub const AUXV_GID: u64 = 13;
+/// Effective group ID.
+pub const AUXV_EGID: u64 = 14;
+/// Address of the platform string.
+pub const AUXV_PLATFORM: u64 = 15;
+/// Hardware capabilities.
+pub const AUXV_HWCAP: u64 = 16;
+/// Frequency of "times".
+pub const AUXV_CLKTCK: u64 = 17;
+/// Secure mode.
+pub const AUXV_SECURE: u64 = 23;
+/// Address of 16 random bytes.
+pub const AUXV_RANDOM: u64 = 25;
+/// Extended hardware capabilities.
+pub const AUXV_HWCAP2: u64 = 26;
+/// Address of the filename of the program.
+pub const AUXV_EXECFN: u64 = 31;
+/// Address of the vDSO.
+pub const AUXV_SYSINFO_EHDR: u64 = 33;
+
+#[derive(Debug, Clone)]
+pub enum ParseCoreNoteError {
+    BrokenPrstatus,
+    BrokenPrpsinfo,
+    BrokenSiginfo,
+    BrokenAuxv,
+    BrokenFileHeader,
+    BrokenFileEntries,
+    BrokenFileNames,
+}
+
+/// Note in the "CORE" namespace, which is written by Linux in core dumps.
+#[derive(Debug, Clone, Copy)]
+pub enum CoreNote<'a, T: Context> {
+    Prstatus(Prstatus<'a, T>),
+    Prpsinfo(Prpsinfo<'a>),
+    Siginfo(Siginfo<'a, T>),
+    Auxv(Auxv<'a, T>),
+    File(FileNote<'a, T>),
+    Unknown(u32, &'a [u8]),
+}
+
+impl<'a, T: Context> CoreNote<'a, T> {
+    /// Returns "None" if the note is not in the "CORE" namespace.
+    pub fn parse(note: Note<'a>) -> Result<Option<Self>, ParseCoreNoteError> {
+        use CoreNote::*;
+        use ParseCoreNoteError::*;
+        if note.name() != b"CORE" {
+            return Ok(None);
+        }
+        let descriptor = note.descriptor();
+        let note = match note.typa() {
+            NOTE_CORE_PRSTATUS => {
+                Prstatus(self::Prstatus::parse(descriptor).ok_or(BrokenPrstatus)?)
+            }
+            NOTE_CORE_PRPSINFO => {
+                Prpsinfo(self::Prpsinfo::parse::<T>(descriptor).ok_or(BrokenPrpsinfo)?)
+            }
+            NOTE_CORE_SIGINFO => Siginfo(self::Siginfo::parse(descriptor).ok_or(BrokenSiginfo)?),
+            NOTE_CORE_AUXV => Auxv(self::Auxv::parse(descriptor).ok_or(BrokenAuxv)?),
+            NOTE_CORE_FILE => File(FileNote::parse(descriptor)?),
+            x => Unknown(x, descriptor),
+        };
+        Ok(Some(note))
+    }
+}
+
+/// Process status of a thread.
+#[derive(Debug, Clone, Copy)]
+pub struct Prstatus<'a, T: Context> {
+    header: &'a PrstatusHeader<T>,
+    registers: &'a [u8],
+}
+
+impl<'a, T: Context> Prstatus<'a, T> {
+    fn parse(descriptor: &'a [u8]) -> Option<Self> {
+        let header: &PrstatusHeader<T> = read(descriptor, 0)?;
+        let registers = &descriptor[core::mem::size_of::<PrstatusHeader<T>>()..];
+        Some(Self { header, registers })
+    }
+    pub fn header(&self) -> &'a PrstatusHeader<T> {
+        self.header
+    }
+    /// Raw bytes after the header, which are general purpose registers and architecture-specific fields.
+    pub fn raw_registers(&self) -> &'a [u8] {
+        self.registers
+    }
+    /// Decodes general purpose registers with "e_machine" of the ELF header.
+    pub fn registers(&self, machine: u16) -> Registers<'a> {
+        use Registers::*;
+        let wide = T::CLASS == Class::Class64;
+        let registers = match machine {
+            ELF_MACHINE_X86_64 if wide => {
+                X86_64Registers::parse::<T>(self.registers, wide).map(X86_64)
+            }
+            ELF_MACHINE_X86 if !wide => I386Registers::parse::<T>(self.registers, wide).map(I386),
+            ELF_MACHINE_AARCH64 if wide => {
+                Aarch64Registers::parse::<T>(self.registers, wide).map(Aarch64)
+            }
+            ELF_MACHINE_RISCV => RiscvRegisters::parse::<T>(self.registers, wide).map(Riscv),
+            _ => None,
+        };
+        registers.unwrap_or(Unknown(self.registers))
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct PrstatusHeader<T: Context> {
+    pub signo: PropU32,
+    pub code: PropU32,
+    pub errno: PropU32,
+    pub cursig: PropU16,
+    pub _pad: PropU16,
+    pub sigpend: T::PropUsize,
+    pub sighold: T::PropUsize,
+    pub pid: PropU32,
+    pub ppid: PropU32,
+    pub pgrp: PropU32,
+    pub sid: PropU32,
+    pub utime: Timeval<T>,
+    pub stime: Timeval<T>,
+    pub cutime: Timeval<T>,
+    pub cstime: Timeval<T>,
+}
+
+impl<T: Context> PrstatusHeader<T> {
+    pub fn signo(&self) -> u32 {
+        T::interpret(self.signo)
+    }
+    pub fn code(&self) -> u32 {
+        T::interpret(self.code)
+    }
+    pub fn errno(&self) -> u32 {
+        T::interpret(self.errno)
+    }
+    /// Current signal.
+    pub fn cursig(&self) -> u16 {
+        T::interpret(self.cursig)
+    }
+    /// Pending signals.
+    pub fn sigpend(&self) -> T::Integer {
+        T::interpret(self.sigpend)
+    }
+    /// Blocked signals.
+    pub fn sighold(&self) -> T::Integer {
+        T::interpret(self.sighold)
+    }
+    pub fn pid(&self) -> u32 {
+        T::interpret(self.pid)
+    }
+    pub fn ppid(&self) -> u32 {
+        T::interpret(self.ppid)
+    }
+    pub fn pgrp(&self) -> u32 {
+        T::interpret(self.pgrp)
+    }
+    pub fn sid(&self) -> u32 {
+        T::interpret(self.sid)
+    }
+    /// User time.
+    pub fn utime(&self) -> &Timeval<T> {
+        &self.utime
+    }
+    /// System time.
+    pub fn stime(&self) -> &Timeval<T> {
+        &self.stime
+    }
+    /// Cumulative user time.
+    pub fn cutime(&self) -> &Timeval<T> {
+        &self.cutime
+    }
+    /// Cumulative system time.
+    pub fn cstime(&self) -> &Timeval<T> {
+        &self.cstime
+    }
+}
+
+unsafe impl<T: Context> Pod for PrstatusHeader<T> {}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct Timeval<T: Context> {
+    pub sec: T::PropUsize,
+    pub usec: T::PropUsize,
+}
+
+impl<T: Context> Timeval<T> {
+    pub fn sec(&self) -> T::Integer {
+        T::interpret(self.sec)
+    }
+    pub fn usec(&self) -> T::Integer {
+        T::interpret(self.usec)
+    }
+}
+
+unsafe impl<T: Context> Pod for Timeval<T> {}
+
+/// General purpose registers, which are zero-extended to 64 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Registers<'a> {
+    X86_64(X86_64Registers),
+    I386(I386Registers),
+    Aarch64(Aarch64Registers),
+    Riscv(RiscvRegisters),
+    /// Registers of an unsupported architecture, or broken registers.
+    Unknown(&'a [u8]),
+}
+
+impl Registers<'_> {
+    /// Program counter.
+    pub fn pc(&self) -> Option<u64> {
+        use Registers::*;
+        match self {
+            X86_64(x) => Some(x.rip),
+            I386(x) => Some(x.eip),
+            Aarch64(x) => Some(x.pc),
+            Riscv(x) => Some(x.pc),
+            Unknown(_) => None,
+        }
+    }
+    /// Stack pointer.
+    pub fn sp(&self) -> Option<u64> {
+        use Registers::*;
+        match self {
+            X86_64(x) => Some(x.rsp),
+            I386(x) => Some(x.esp),
+            Aarch64(x) => Some(x.sp),
+            Riscv(x) => Some(x.sp),
+            Unknown(_) => None,
+        }
+    }
+}
+
+/// Reads words of 8 bytes if "wide" is true, or 4 bytes otherwise.
+fn words<T: Context>(content: &[u8], wide: bool) -> impl Iterator<Item = u64> + '_ {
+    let size = if wide { 8 } else { 4 };
+    content.chunks_exact(size).map(move |x| match wide {
+        true => T::interpret(PropU64::try_from(x).unwrap()),
+        false => T::interpret(PropU32::try_from(x).unwrap()) as u64,
+    })
+}
+
+macro_rules! registers {
+    ($(#[$attr:meta])* $name:ident { $($field:ident),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name {
+            $(pub $field: u64,)*
+        }
+
+        impl $name {
+            fn parse<T: Context>(content: &[u8], wide: bool) -> Option<Self> {
+                let mut words = words::<T>(content, wide);
+                Some(Self {
+                    $($field: words.next()?,)*
+                })
+            }
+        }
+    };
+}
+
+registers! {
+    /// Registers of x86-64, in the order of "user_regs_struct".
+    X86_64Registers {
+        r15, r14, r13, r12, rbp, rbx, r11, r10, r9, r8, rax, rcx, rdx, rsi, rdi, orig_rax,
+        rip, cs, eflags, rsp, ss, fs_base, gs_base, ds, es, fs, gs,
+    }
+}
+
+registers! {
+    /// Registers of i386, in the order of "user_regs_struct".
+    I386Registers {
+        ebx, ecx, edx, esi, edi, ebp, eax, ds, es, fs, gs, orig_eax, eip, cs, eflags, esp, ss,
+    }
+}
+
+registers! {
+    /// Registers of AArch64, in the order of "user_pt_regs".
+    Aarch64Registers {
+        x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18,
+        x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, sp, pc, pstate,
+    }
+}
+
+registers! {
+    /// Registers of RISC-V, in the order of "user_regs_struct".
+    RiscvRegisters {
+        pc, ra, sp, gp, tp, t0, t1, t2, s0, s1, a0, a1, a2, a3, a4, a5, a6, a7, s2, s3, s4, s5,
+        s6, s7, s8, s9, s10, s11, t3, t4, t5, t6,
+    }
+}
+
+/// Process information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prpsinfo<'a> {
+    /// Numeric process state.
+    pub state: u8,
+    /// Character of the process state, such as "R", "S" and "D".
+    pub sname: u8,
+    pub zombie: u8,
+    pub nice: i8,
+    pub flag: u64,
+    pub uid: u32,
+    pub gid: u32,
+    pub pid: u32,
+    pub ppid: u32,
+    pub pgrp: u32,
+    pub sid: u32,
+    /// Filename of the executable, which is truncated to 15 bytes.
+    pub fname: &'a [u8],
+    /// Arguments separated by spaces, which are truncated to 79 bytes.
+    pub psargs: &'a [u8],
+}
+
+impl<'a> Prpsinfo<'a> {
+    fn parse<T: Context>(descriptor: &'a [u8]) -> Option<Self> {
+        let (&[state, sname, zombie, nice], _) = descriptor.split_first_chunk::<4>()?;
+        let (flag, mut offset) = match T::CLASS {
+            Class::Class32 => (T::interpret(*read::<PropU32>(descriptor, 4)?) as u64, 8),
+            Class::Class64 => (T::interpret(*read::<PropU64>(descriptor, 8)?), 16),
+        };
+        // "uid" and "gid" are 16 bits on some 32-bit architectures, such as i386 and ARM
+        let (uid, gid) = match descriptor.len().checked_sub(offset)? {
+            116 => {
+                let uid = T::interpret(*read::<PropU16>(descriptor, offset)?) as u32;
+                let gid = T::interpret(*read::<PropU16>(descriptor, offset + 2)?) as u32;
+                offset += 4;
+                (uid, gid)
+            }
+            _ => {
+                let uid = T::interpret(*read::<PropU32>(descriptor, offset)?);
+                let gid = T::interpret(*read::<PropU32>(descriptor, offset + 4)?);
+                offset += 8;
+                (uid, gid)
+            }
+        };
+        let ids: &[PropU32] = read_n(descriptor, offset, 4)?;
+        offset += 16;
+        let fname: &[u8] = read_n(descriptor, offset, 16)?;
+        offset += 16;
+        let psargs: &[u8] = read_n(descriptor, offset, 80)?;
+        Some(Self {
+            state,
+            sname,
+            zombie,
+            nice: nice as i8,
+            flag,
+            uid,
+            gid,
+            pid: T::interpret(ids[0]),
+            ppid: T::interpret(ids[1]),
+            pgrp: T::interpret(ids[2]),
+            sid: T::interpret(ids[3]),
+            fname: terminate(fname).unwrap_or(fname),
+            psargs: terminate(psargs).unwrap_or(psargs),
+        })
+    }
+}
+
+/// Signal information, which is "siginfo_t" of the signal that caused the core dump.
+#[derive(Debug, Clone, Copy)]
+pub struct Siginfo<'a, T: Context> {
+    header: &'a SiginfoHeader<T>,
+    fields: &'a [u8],
+}
+
+impl<'a, T: Context> Siginfo<'a, T> {
+    fn parse(descriptor: &'a [u8]) -> Option<Self> {
+        let header: &SiginfoHeader<T> = read(descriptor, 0)?;
+        let fields = &descriptor[core::mem::size_of::<SiginfoHeader<T>>()..];
+        Some(Self { header, fields })
+    }
+    pub fn header(&self) -> &'a SiginfoHeader<T> {
+        self.header
+    }
+    /// Raw bytes of the union after the header.
+    pub fn fields(&self) -> &'a [u8] {
+        self.fields
+    }
+    /// Faulting address, if the signal is a fault ("SIGILL", "SIGTRAP", "SIGBUS", "SIGFPE" and "SIGSEGV") raised by the kernel.
+    pub fn addr(&self) -> Option<T::Integer> {
+        if !matches!(self.header.signo(), 4 | 5 | 7 | 8 | 11) || self.header.code() <= 0 {
+            return None;
+        }
+        Some(read::<ArrayEntry<T>>(self.fields, 0)?.value())
+    }
+    /// Process ID and user ID of the sender, if the signal is sent by a process.
+    pub fn sender(&self) -> Option<(u32, u32)> {
+        if self.header.code() > 0 {
+            return None;
+        }
+        let ids: &[PropU32] = read_n(self.fields, 0, 2)?;
+        Some((T::interpret(ids[0]), T::interpret(ids[1])))
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct SiginfoHeader<T: Context> {
+    pub signo: PropU32,
+    pub errno: PropU32,
+    pub code: PropU32,
+    pub _pad: T::PropU32If64,
+}
+
+impl<T: Context> SiginfoHeader<T> {
+    pub fn signo(&self) -> i32 {
+        T::interpret(self.signo) as i32
+    }
+    pub fn errno(&self) -> i32 {
+        T::interpret(self.errno) as i32
+    }
+    pub fn code(&self) -> i32 {
+        T::interpret(self.code) as i32
+    }
+}
+
+unsafe impl<T: Context> Pod for SiginfoHeader<T> {}
+
+/// Auxiliary vector.
+#[derive(Debug, Clone, Copy)]
+pub struct Auxv<'a, T: Context> {
+    entries: &'a [AuxvEntry<T>],
+}
+
+impl<'a, T: Context> Auxv<'a, T> {
+    fn parse(descriptor: &'a [u8]) -> Option<Self> {
+        let entries = read_s(descriptor)?;
+        Some(Self { entries })
+    }
+    pub fn entries(&self) -> &'a [AuxvEntry<T>] {
+        self.entries
+    }
+    /// Iterates over entries until "AUXV_NULL".
+    pub fn iter(&self) -> impl Iterator<Item = &'a AuxvEntry<T>> {
+        self.entries
+            .iter()
+            .take_while(|x| x.typa().into() != AUXV_NULL)
+    }
+    /// Finds the value of the first entry of the type.
+    pub fn find(&self, typa: u64) -> Option<T::Integer> {
+        self.iter()
+            .find(|x| x.typa().into() == typa)
+            .map(|x| x.value())
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct AuxvEntry<T: Context> {
+    pub typa: T::PropUsize,
+    pub value: T::PropUsize,
+}
+
+impl<T: Context> AuxvEntry<T> {
+    pub fn typa(&self) -> T::Integer {
+        T::interpret(self.typa)
+    }
+    pub fn value(&self) -> T::Integer {
+        T::interpret(self.value)
+    }
+}
+
+unsafe impl<T: Context> Pod for AuxvEntry<T> {}
+
+/// Mapped files.
+#[derive(Debug, Clone, Copy)]
+pub struct FileNote<'a, T: Context> {
+    header: &'a FileHeader<T>,
+    entries: &'a [FileEntry<T>],
+    names: &'a [u8],
+}
+
+impl<'a, T: Context> FileNote<'a, T> {
+    fn parse(descriptor: &'a [u8]) -> Result<Self, ParseCoreNoteError> {
+        use ParseCoreNoteError::*;
+        let header: &FileHeader<T> = read(descriptor, 0).ok_or(BrokenFileHeader)?;
+        let offset = core::mem::size_of::<FileHeader<T>>();
+        let count = usize::try_from(header.count().into()).map_err(|_| BrokenFileEntries)?;
+        let entries: &[FileEntry<T>] =
+            read_n(descriptor, offset, count).ok_or(BrokenFileEntries)?;
+        let offset = offset + core::mem::size_of::<FileEntry<T>>() * count;
+        let names = &descriptor[offset..];
+        let file = Self {
+            header,
+            entries,
+            names,
+        };
+        if file.names().any(|x| x.is_none()) {
+            return Err(BrokenFileNames);
+        }
+        Ok(file)
+    }
+    pub fn header(&self) -> &'a FileHeader<T> {
+        self.header
+    }
+    pub fn entries(&self) -> &'a [FileEntry<T>] {
+        self.entries
+    }
+    pub fn iter(&self) -> impl Iterator<Item = MappedFile<'a, T>> {
+        let page_size = self.header.page_size();
+        self.entries
+            .iter()
+            .zip(self.names().map(Option::unwrap))
+            .map(move |(entry, name)| MappedFile {
+                entry,
+                name,
+                page_size,
+            })
+    }
+    fn names(&self) -> impl Iterator<Item = Option<&'a [u8]>> {
+        let mut rest = self.names;
+        self.entries.iter().map(move |_| {
+            let name = terminate(rest)?;
+            rest = &rest[name.len() + 1..];
+            Some(name)
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct FileHeader<T: Context> {
+    pub count: T::PropUsize,
+    pub page_size: T::PropUsize,
+}
+
+impl<T: Context> FileHeader<T> {
+    pub fn count(&self) -> T::Integer {
+        T::interpret(self.count)
+    }
+    pub fn page_size(&self) -> T::Integer {
+        T::interpret(self.page_size)
+    }
+}
+
+unsafe impl<T: Context> Pod for FileHeader<T> {}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct FileEntry<T: Context> {
+    pub start: T::PropUsize,
+    pub end: T::PropUsize,
+    pub page_offset: T::PropUsize,
+}
+
+impl<T: Context> FileEntry<T> {
+    pub fn start(&self) -> T::Integer {
+        T::interpret(self.start)
+    }
+    pub fn end(&self) -> T::Integer {
+        T::interpret(self.end)
+    }
+    /// File offset in pages.
+    pub fn page_offset(&self) -> T::Integer {
+        T::interpret(self.page_offset)
+    }
+}
+
+unsafe impl<T: Context> Pod for FileEntry<T> {}
+
+/// Mapped range of a file.
+#[derive(Debug, Clone, Copy)]
+pub struct MappedFile<'a, T: Context> {
+    entry: &'a FileEntry<T>,
+    name: &'a [u8],
+    page_size: T::Integer,
+}
+
+impl<'a, T: Context> MappedFile<'a, T> {
+    pub fn entry(&self) -> &'a FileEntry<T> {
+        self.entry
+    }
+    pub fn name(&self) -> &'a [u8] {
+        self.name
+    }
+    pub fn start(&self) -> u64 {
+        self.entry.start().into()
+    }
+    pub fn end(&self) -> u64 {
+        self.entry.end().into()
+    }
+    /// File offset in bytes.
+    pub fn offset(&self) -> u64 {
+        let page_offset: u64 = self.entry.page_offset().into();
+        page_offset.wrapping_mul(self.page_size.into())
+    }
+}
@@ -0,0 +1,143 @@
+use crate::context::*;
+use crate::debug_link::{DebugAltLink, DebugLink};
+use crate::elf::{Elf, Variant};
+use crate::gnu_note::BuildId;
+use std::path::{Path, PathBuf};
+use std::vec::Vec;
+
+/// Global debug directory of most distributions.
+pub const DEBUG_DIRECTORY: &str = "/usr/lib/debug";
+
+/// Resolver of separate debug files.
+#[derive(Debug, Clone)]
+pub struct DebugFileResolver {
+    directories: Vec<PathBuf>,
+}
+
+impl Default for DebugFileResolver {
+    fn default() -> Self {
+        Self::new(std::vec![PathBuf::from(DEBUG_DIRECTORY)])
+    }
+}
+
+impl DebugFileResolver {
+    /// Debug files are searched in global debug directories, such as "/usr/lib/debug".
+    pub fn new(directories: Vec<PathBuf>) -> Self {
+        Self { directories }
+    }
+    pub fn directories(&self) -> &[PathBuf] {
+        &self.directories
+    }
+    /// Finds the debug file of the ELF file at the path, by build ID first and ".gnu_debuglink" then.
+    pub fn resolve<T: Context>(&self, elf: Variant<'_, T>, path: &Path) -> Option<DebugFile> {
+        if let Some(x) = BuildId::find(elf).and_then(|x| self.by_build_id(x)) {
+            return Some(x);
+        }
+        let link = DebugLink::find(elf)?.ok()?;
+        self.by_debug_link(link, path)
+    }
+    /// Finds the shared debug file of the debug file at the path, by ".gnu_debugaltlink".
+    pub fn resolve_alt<T: Context>(&self, elf: Variant<'_, T>, path: &Path) -> Option<DebugFile> {
+        let link = DebugAltLink::find(elf)?.ok()?;
+        self.by_debug_alt_link(link, path)
+    }
+    /// Tries "{directory}/.build-id/xx/rest.debug" in global debug directories.
+    ///
+    /// The build ID of the debug file is checked.
+    pub fn by_build_id(&self, build_id: BuildId<'_>) -> Option<DebugFile> {
+        let (first, rest) = build_id.bytes().split_first()?;
+        if rest.is_empty() {
+            return None;
+        }
+        let mut name = std::format!("{:02x}/", first);
+        for x in rest {
+            name.push_str(&std::format!("{:02x}", x));
+        }
+        name.push_str(".debug");
+        self.directories.iter().find_map(|x| {
+            let file = DebugFile::open(x.join(".build-id").join(&name))?;
+            (file.build_id()? == build_id).then_some(file)
+        })
+    }
+    /// Tries the directory of the ELF file at the path, its ".debug" subdirectory, and the same directory in global debug directories.
+    ///
+    /// The CRC-32 of the debug file is checked. Names with directories, which escape these directories, are rejected.
+    pub fn by_debug_link(&self, link: DebugLink<'_>, path: &Path) -> Option<DebugFile> {
+        let name = to_path(link.name())?;
+        if name.file_name() != Some(name.as_os_str()) {
+            return None;
+        }
+        let directory = path.parent()?;
+        let mut candidates = std::vec![directory.join(name), directory.join(".debug").join(name)];
+        let canonical = directory.canonicalize().ok();
+        if let Some(relative) = canonical.as_deref().and_then(|x| x.strip_prefix("/").ok()) {
+            for x in self.directories.iter() {
+                candidates.push(x.join(relative).join(name));
+            }
+        }
+        candidates.into_iter().find_map(|x| {
+            let file = DebugFile::open(x)?;
+            link.check(file.data()).then_some(file)
+        })
+    }
+    /// Tries the path, which is relative to the directory of the debug file at the path if it's not absolute, and the build ID then.
+    ///
+    /// The build ID of the shared debug file is checked.
+    pub fn by_debug_alt_link(&self, link: DebugAltLink<'_>, path: &Path) -> Option<DebugFile> {
+        let build_id = link.build_id();
+        let name = to_path(link.name())?;
+        let candidate = path.parent().map(|x| x.join(name));
+        candidate
+            .and_then(DebugFile::open)
+            .filter(|x| x.build_id() == Some(build_id))
+            .or_else(|| self.by_build_id(build_id))
+    }
+}
+
+/// Debug file, which is read into memory.
+#[derive(Debug, Clone)]
+pub struct DebugFile {
+    path: PathBuf,
+    data: Vec<u8>,
+}
+
+impl DebugFile {
+    /// Returns "None" if the file cannot be read or is not an ELF file.
+    pub fn open(path: PathBuf) -> Option<Self> {
+        let data = std::fs::read(&path).ok()?;
+        Elf::parse(&data).ok()?;
+        Some(Self { path, data })
+    }
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn elf(&self) -> Elf<'_> {
+        Elf::parse(&self.data).unwrap()
+    }
+    /// Returns the debug file if it matches the context, which is the context of the ELF file usually.
+    pub fn variant<T: Context>(&self) -> Option<Variant<'_, T>> {
+        Variant::checked_parse(&self.data)
+    }
+    pub fn build_id(&self) -> Option<BuildId<'_>> {
+        match self.elf() {
+            Elf::Little32(x) => BuildId::find(x),
+            Elf::Little64(x) => BuildId::find(x),
+            Elf::Big32(x) => BuildId::find(x),
+            Elf::Big64(x) => BuildId::find(x),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn to_path(name: &[u8]) -> Option<&Path> {
+    use std::os::unix::ffi::OsStrExt;
+    Some(Path::new(std::ffi::OsStr::from_bytes(name)))
+}
+
+#[cfg(not(unix))]
+fn to_path(name: &[u8]) -> Option<&Path> {
+    core::str::from_utf8(name).ok().map(Path::new)
+}
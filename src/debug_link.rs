@@ -0,0 +1,108 @@
+use crate::context::PropU32;
+use crate::context::*;
+use crate::elf::Variant;
+use crate::gnu_note::BuildId;
+use crate::section::Sections;
+use crate::utils::{align, read, terminate};
+
+#[derive(Debug, Clone)]
+pub enum ParseDebugLinkError {
+    BrokenName,
+    BrokenCrc,
+}
+
+#[derive(Debug, Clone)]
+pub enum ParseDebugAltLinkError {
+    BrokenName,
+}
+
+/// Separate debug file, given by the ".gnu_debuglink" section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugLink<'a> {
+    name: &'a [u8],
+    crc: u32,
+}
+
+impl<'a> DebugLink<'a> {
+    pub fn parse<T: Context>(content: &'a [u8]) -> Result<Self, ParseDebugLinkError> {
+        use ParseDebugLinkError::*;
+        let name = terminate(content).ok_or(BrokenName)?;
+        let offset = align(name.len() + 1, 4);
+        let crc = T::interpret(*read::<PropU32>(content, offset).ok_or(BrokenCrc)?);
+        Ok(Self { name, crc })
+    }
+    /// Finds the ".gnu_debuglink" section.
+    pub fn find<T: Context>(elf: Variant<'a, T>) -> Option<Result<Self, ParseDebugLinkError>> {
+        let sections = Sections::parse(elf).ok()??;
+        let section = sections.by_name(b".gnu_debuglink")?;
+        Some(Self::parse::<T>(section.content()))
+    }
+    /// File name of the debug file, without directories.
+    pub fn name(&self) -> &'a [u8] {
+        self.name
+    }
+    /// CRC-32 of the whole debug file.
+    pub fn crc(&self) -> u32 {
+        self.crc
+    }
+    /// Checks the CRC-32 of a debug file.
+    pub fn check(&self, data: &[u8]) -> bool {
+        crc32(data) == self.crc
+    }
+}
+
+/// Shared debug file, given by the ".gnu_debugaltlink" section, which is usually produced by "dwz".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugAltLink<'a> {
+    name: &'a [u8],
+    build_id: BuildId<'a>,
+}
+
+impl<'a> DebugAltLink<'a> {
+    pub fn parse(content: &'a [u8]) -> Result<Self, ParseDebugAltLinkError> {
+        use ParseDebugAltLinkError::*;
+        let name = terminate(content).ok_or(BrokenName)?;
+        let build_id = BuildId(&content[name.len() + 1..]);
+        Ok(Self { name, build_id })
+    }
+    /// Finds the ".gnu_debugaltlink" section.
+    pub fn find<T: Context>(elf: Variant<'a, T>) -> Option<Result<Self, ParseDebugAltLinkError>> {
+        let sections = Sections::parse(elf).ok()??;
+        let section = sections.by_name(b".gnu_debugaltlink")?;
+        Some(Self::parse(section.content()))
+    }
+    /// Path of the shared debug file, which is relative to the directory of the debug file if it's not absolute.
+    pub fn name(&self) -> &'a [u8] {
+        self.name
+    }
+    /// Build ID of the shared debug file.
+    pub fn build_id(&self) -> BuildId<'a> {
+        self.build_id
+    }
+}
+
+/// CRC-32 (IEEE 802.3), which is used by ".gnu_debuglink".
+pub fn crc32(data: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut x = i as u32;
+            let mut j = 0;
+            while j < 8 {
+                x = if x & 1 != 0 {
+                    0xedb88320 ^ (x >> 1)
+                } else {
+                    x >> 1
+                };
+                j += 1;
+            }
+            table[i] = x;
+            i += 1;
+        }
+        table
+    };
+    !data.iter().fold(!0u32, |crc, &x| {
+        TABLE[((crc ^ x as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
@@ -0,0 +1,198 @@
+use crate::context::*;
+use crate::elf::{Elf, Variant};
+use crate::gnu_note::BuildId;
+use core::fmt::Debug;
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::path::{Path, PathBuf};
+use std::string::String;
+use std::vec::Vec;
+
+/// Counter of temporary files, which tells apart concurrent fetches in a process.
+static TEMPORARY: AtomicU64 = AtomicU64::new(0);
+
+/// Transport of HTTP requests, which is implemented by users.
+pub trait Transport {
+    type Error: Debug;
+
+    /// Sends a "GET" request, and returns "None" if the server responds "404 Not Found".
+    fn get(&self, url: &str) -> Result<Option<Vec<u8>>, Self::Error>;
+}
+
+#[derive(Debug)]
+pub enum DebuginfodError<E> {
+    BadPropertyBuildId,
+    BadPropertyPath,
+    FromTransport(E),
+    FromCache(std::io::Error),
+}
+
+/// Artifact of the debuginfod protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Artifact<'a> {
+    /// "/buildid/{id}/debuginfo".
+    Debuginfo,
+    /// "/buildid/{id}/executable".
+    Executable,
+    /// "/buildid/{id}/source/{path}", where the path is absolute.
+    Source(&'a str),
+}
+
+/// Client of the debuginfod protocol, with an on-disk cache.
+#[derive(Debug, Clone)]
+pub struct DebuginfodClient<X: Transport> {
+    transport: X,
+    urls: Vec<String>,
+    cache: PathBuf,
+}
+
+impl<X: Transport> DebuginfodClient<X> {
+    /// Servers are tried in order, and artifacts are cached in the directory.
+    pub fn new(transport: X, urls: Vec<String>, cache: PathBuf) -> Self {
+        Self {
+            transport,
+            urls,
+            cache,
+        }
+    }
+    /// Servers are given by "DEBUGINFOD_URLS", and the cache is given by "DEBUGINFOD_CACHE_PATH", "XDG_CACHE_HOME" or "HOME".
+    pub fn from_env(transport: X) -> Self {
+        let urls = std::env::var("DEBUGINFOD_URLS").unwrap_or_default();
+        let urls = urls.split_whitespace().map(String::from).collect();
+        let cache = if let Some(x) = std::env::var_os("DEBUGINFOD_CACHE_PATH") {
+            PathBuf::from(x)
+        } else if let Some(x) = std::env::var_os("XDG_CACHE_HOME") {
+            PathBuf::from(x).join("debuginfod_client")
+        } else {
+            let home = std::env::var_os("HOME").unwrap_or_default();
+            PathBuf::from(home).join(".cache/debuginfod_client")
+        };
+        Self::new(transport, urls, cache)
+    }
+    pub fn transport(&self) -> &X {
+        &self.transport
+    }
+    pub fn urls(&self) -> &[String] {
+        &self.urls
+    }
+    pub fn cache(&self) -> &Path {
+        &self.cache
+    }
+    /// Fetches the debug file of the ELF file, which is keyed by its build ID.
+    pub fn debuginfo_for<T: Context>(
+        &self,
+        elf: Variant<'_, T>,
+    ) -> Result<Option<PathBuf>, DebuginfodError<X::Error>> {
+        let build_id = BuildId::find(elf).ok_or(DebuginfodError::BadPropertyBuildId)?;
+        self.fetch(build_id, Artifact::Debuginfo)
+    }
+    pub fn debuginfo(
+        &self,
+        build_id: BuildId<'_>,
+    ) -> Result<Option<PathBuf>, DebuginfodError<X::Error>> {
+        self.fetch(build_id, Artifact::Debuginfo)
+    }
+    pub fn executable(
+        &self,
+        build_id: BuildId<'_>,
+    ) -> Result<Option<PathBuf>, DebuginfodError<X::Error>> {
+        self.fetch(build_id, Artifact::Executable)
+    }
+    pub fn source(
+        &self,
+        build_id: BuildId<'_>,
+        path: &str,
+    ) -> Result<Option<PathBuf>, DebuginfodError<X::Error>> {
+        self.fetch(build_id, Artifact::Source(path))
+    }
+    /// Returns the path of the artifact in the cache, fetching it if it's not cached.
+    ///
+    /// Returns "None" if no server has the artifact, or the last error of the transport if every server fails.
+    ///
+    /// Debug files and executables without the build ID are not cached, as if the server doesn't have them.
+    pub fn fetch(
+        &self,
+        build_id: BuildId<'_>,
+        artifact: Artifact<'_>,
+    ) -> Result<Option<PathBuf>, DebuginfodError<X::Error>> {
+        use DebuginfodError::*;
+        if build_id.bytes().is_empty() {
+            return Err(BadPropertyBuildId);
+        }
+        let (suffix, name) = match artifact {
+            Artifact::Debuginfo => (String::from("debuginfo"), String::from("debuginfo")),
+            Artifact::Executable => (String::from("executable"), String::from("executable")),
+            Artifact::Source(path) if path.starts_with('/') => (
+                std::format!("source{}", encode(path)),
+                // the same naming as "elfutils"
+                std::format!("source{}", path.replace('/', "#")),
+            ),
+            Artifact::Source(_) => return Err(BadPropertyPath),
+        };
+        let directory = self.cache.join(std::format!("{:x}", build_id));
+        let cached = directory.join(&name);
+        if cached.is_file() {
+            return Ok(Some(cached));
+        }
+        let mut missing = false;
+        let mut error = None;
+        for url in self.urls.iter() {
+            let url = std::format!(
+                "{}/buildid/{:x}/{}",
+                url.trim_end_matches('/'),
+                build_id,
+                suffix
+            );
+            match self.transport.get(&url) {
+                Ok(Some(content))
+                    if !matches!(artifact, Artifact::Source(_))
+                        && !has_build_id(&content, build_id) =>
+                {
+                    missing = true
+                }
+                Ok(Some(content)) => {
+                    std::fs::create_dir_all(&directory).map_err(FromCache)?;
+                    // renaming is atomic, so that a partial file is never cached
+                    let temporary = directory.join(std::format!(
+                        ".{}.{}.{}",
+                        name,
+                        std::process::id(),
+                        TEMPORARY.fetch_add(1, Ordering::Relaxed)
+                    ));
+                    std::fs::write(&temporary, content).map_err(FromCache)?;
+                    std::fs::rename(&temporary, &cached).map_err(FromCache)?;
+                    return Ok(Some(cached));
+                }
+                Ok(None) => missing = true,
+                Err(e) => error = Some(e),
+            }
+        }
+        match error {
+            Some(e) if !missing => Err(FromTransport(e)),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn has_build_id(content: &[u8], build_id: BuildId<'_>) -> bool {
+    let found = match Elf::parse(content) {
+        Ok(Elf::Little32(x)) => BuildId::find(x),
+        Ok(Elf::Little64(x)) => BuildId::find(x),
+        Ok(Elf::Big32(x)) => BuildId::find(x),
+        Ok(Elf::Big64(x)) => BuildId::find(x),
+        Err(_) => None,
+    };
+    found == Some(build_id)
+}
+
+/// Percent-encodes a path, keeping unreserved characters and slashes.
+fn encode(path: &str) -> String {
+    let mut encoded = String::new();
+    for &x in path.as_bytes() {
+        if x.is_ascii_alphanumeric() || b"-._~/".contains(&x) {
+            encoded.push(x as char);
+        } else {
+            encoded.push_str(&std::format!("%{:02X}", x));
+        }
+    }
+    encoded
+}
@@ -0,0 +1,325 @@
+use crate::context::*;
+use crate::debuginfod::Artifact;
+use crate::elf::{Elf, ElfType, Variant};
+use crate::gnu_note::BuildId;
+use crate::program::{ProgramType, Programs};
+use crate::section::{SectionFlags32, SectionType, Sections};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::vec::Vec;
+
+/// Maximum depth of directories visited by "DebuginfodIndex::scan".
+pub const MAX_SCAN_DEPTH: usize = 32;
+
+/// Maximum size of the request line and headers of a request.
+const MAX_REQUEST_SIZE: u64 = 8192;
+
+/// Maximum number of headers of a request.
+const MAX_REQUEST_HEADERS: usize = 64;
+
+/// Timeout of each read of a request and each write of a response, so that stalled clients do not hold workers.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Kind of an ELF file, which decides the artifacts it's served as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileKind {
+    /// The file is an executable or a shared object, whose code is not stripped.
+    pub executable: bool,
+    /// The file has DWARF, which is probably a separate debug file.
+    pub debuginfo: bool,
+}
+
+impl FileKind {
+    pub fn classify<T: Context>(elf: Variant<'_, T>) -> Self {
+        let mut executable = false;
+        let mut debuginfo = false;
+        let loadable = matches!(
+            elf.header().checked_type(),
+            Some(ElfType::Exec) | Some(ElfType::Dyn)
+        );
+        if let Ok(Some(sections)) = Sections::parse(elf) {
+            debuginfo = sections
+                .find_all(|name, section| {
+                    section.header().typa() != SectionType::Nobits
+                        && (name.starts_with(b".debug_") || name.starts_with(b".zdebug_"))
+                })
+                .any(|x| !x.content().is_empty());
+            for (_, section) in sections.iter() {
+                let section = match section {
+                    Ok(x) if x.header().typa() != SectionType::Nobits => x,
+                    _ => continue,
+                };
+                let flags: T::Integer = section.header().flags().into();
+                let flags: u64 = flags.into();
+                // the flags are the same in both classes
+                let code = u64::from(SectionFlags32::ALLOC.0 | SectionFlags32::EXECINSTR.0);
+                executable |= loadable && flags & code == code && !section.content().is_empty();
+            }
+        } else if let Ok(Some(programs)) = Programs::parse(elf) {
+            // without section headers, the file is stripped
+            for (_, program) in programs.iter() {
+                let program = match program {
+                    Ok(x) if x.header().typa() == ProgramType::Load => x,
+                    _ => continue,
+                };
+                executable |= loadable && !program.content().is_empty();
+            }
+        }
+        Self {
+            executable,
+            debuginfo,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Entry {
+    executable: Option<PathBuf>,
+    debuginfo: Option<PathBuf>,
+}
+
+/// Index of local ELF files by build ID, which answers debuginfod requests.
+///
+/// Sources are not indexed, so requests for sources are answered with "404 Not Found".
+#[derive(Debug, Clone, Default)]
+pub struct DebuginfodIndex {
+    entries: HashMap<Vec<u8>, Entry>,
+}
+
+impl DebuginfodIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Number of build IDs.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// Scans the directory recursively, and returns the number of ELF files indexed.
+    ///
+    /// Symbolic links are not followed, and each directory is visited once and within "MAX_SCAN_DEPTH" levels. Files and subdirectories which cannot be read are skipped.
+    pub fn scan(&mut self, directory: &Path) -> std::io::Result<usize> {
+        let mut visited = HashSet::new();
+        self.scan_directory(directory, 0, &mut visited)
+    }
+    fn scan_directory(
+        &mut self,
+        directory: &Path,
+        depth: usize,
+        visited: &mut HashSet<(u64, u64)>,
+    ) -> std::io::Result<usize> {
+        let entries = std::fs::read_dir(directory)?;
+        // a directory is reachable twice through bind mounts
+        if let Some(identity) = identity(directory) {
+            if !visited.insert(identity) {
+                return Ok(0);
+            }
+        }
+        let mut count = 0usize;
+        for entry in entries {
+            let entry = match entry {
+                Ok(x) => x,
+                Err(_) => continue,
+            };
+            let typa = match entry.file_type() {
+                Ok(x) => x,
+                Err(_) => continue,
+            };
+            if typa.is_dir() {
+                if depth < MAX_SCAN_DEPTH {
+                    count += self
+                        .scan_directory(&entry.path(), depth + 1, visited)
+                        .unwrap_or(0);
+                }
+            } else if typa.is_file() {
+                let mut magic = [0u8; 4];
+                let readable = std::fs::File::open(entry.path())
+                    .and_then(|mut x| x.read_exact(&mut magic))
+                    .is_ok();
+                if !readable || &magic != b"\x7fELF" {
+                    continue;
+                }
+                let data = match std::fs::read(entry.path()) {
+                    Ok(x) => x,
+                    Err(_) => continue,
+                };
+                count += self.add(entry.path(), &data) as usize;
+            }
+        }
+        Ok(count)
+    }
+    /// Indexes the ELF file at the path, and returns "false" if it has no build ID or it's neither an executable nor a debug file.
+    ///
+    /// The first file indexed wins if files share the same build ID.
+    pub fn add(&mut self, path: PathBuf, data: &[u8]) -> bool {
+        fn helper<T: Context>(elf: Variant<'_, T>) -> Option<(&[u8], FileKind)> {
+            Some((BuildId::find(elf)?.bytes(), FileKind::classify(elf)))
+        }
+        let parsed = match Elf::parse(data) {
+            Ok(Elf::Little32(x)) => helper(x),
+            Ok(Elf::Little64(x)) => helper(x),
+            Ok(Elf::Big32(x)) => helper(x),
+            Ok(Elf::Big64(x)) => helper(x),
+            Err(_) => None,
+        };
+        let (build_id, kind) = match parsed {
+            Some((build_id, kind)) if kind.executable || kind.debuginfo => (build_id, kind),
+            _ => return false,
+        };
+        let entry = self.entries.entry(build_id.to_vec()).or_default();
+        if kind.executable && entry.executable.is_none() {
+            entry.executable = Some(path.clone());
+        }
+        if kind.debuginfo && entry.debuginfo.is_none() {
+            entry.debuginfo = Some(path);
+        }
+        true
+    }
+    pub fn get(&self, build_id: BuildId<'_>, artifact: Artifact<'_>) -> Option<&Path> {
+        let entry = self.entries.get(build_id.bytes())?;
+        match artifact {
+            Artifact::Debuginfo => entry.debuginfo.as_deref(),
+            Artifact::Executable => entry.executable.as_deref(),
+            Artifact::Source(_) => None,
+        }
+    }
+    /// Resolves the path of a request, such as "/buildid/{id}/debuginfo".
+    pub fn route(&self, path: &str) -> Option<&Path> {
+        let rest = path.strip_prefix("/buildid/")?;
+        let (id, artifact) = rest.split_once('/')?;
+        let artifact = match artifact {
+            "debuginfo" => Artifact::Debuginfo,
+            "executable" => Artifact::Executable,
+            _ => return None,
+        };
+        if id.len() % 2 != 0 || !id.is_ascii() {
+            return None;
+        }
+        let id = (0..id.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&id[i..i + 2], 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+        self.get(BuildId(&id), artifact)
+    }
+    /// Serves debuginfod requests on the listener with a pool of "workers" threads, each of which answers one connection at a time.
+    ///
+    /// Errors of accepting a connection are skipped, so it returns only if the listener is unusable, after all workers stop.
+    /// A worker that panics stops, and it's reported as an error if no worker stops for the listener.
+    pub fn serve(&self, listener: &TcpListener, workers: usize) -> std::io::Result<()> {
+        std::thread::scope(|scope| {
+            let workers = (0..workers.max(1))
+                .map(|_| scope.spawn(|| self.work(listener)))
+                .collect::<Vec<_>>();
+            let mut panicked = None;
+            for worker in workers {
+                match worker.join() {
+                    Ok(error) => return Err(error),
+                    Err(_) => panicked = Some(std::io::Error::other("a worker panicked")),
+                }
+            }
+            Err(panicked.unwrap())
+        })
+    }
+    fn work(&self, listener: &TcpListener) -> std::io::Error {
+        loop {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    // the connection is closed on errors, which are about this client only
+                    let _ = self.handle(stream);
+                }
+                Err(e) if matches!(e.kind(), ErrorKind::InvalidInput | ErrorKind::Unsupported) => {
+                    return e;
+                }
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        ErrorKind::ConnectionAborted
+                            | ErrorKind::ConnectionReset
+                            | ErrorKind::Interrupted
+                            | ErrorKind::TimedOut
+                            | ErrorKind::WouldBlock
+                    ) => {}
+                // such as running out of file descriptors, which lasts until other connections are closed
+                Err(_) => std::thread::sleep(Duration::from_millis(100)),
+            }
+        }
+    }
+    /// Answers one HTTP request on the connection, which is closed then.
+    ///
+    /// Requests are answered with "400 Bad Request" if their request lines and headers exceed 8 KiB or 64 headers.
+    pub fn handle(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+        stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+        let mut reader = BufReader::new((&stream).take(MAX_REQUEST_SIZE));
+        let mut line = std::string::String::new();
+        reader.read_line(&mut line)?;
+        let mut parts = line.split_whitespace();
+        let (method, path) = (parts.next(), parts.next());
+        // the rest of the request is ignored, but it must end within the limits
+        let mut complete = false;
+        if line.ends_with('\n') {
+            for _ in 0..=MAX_REQUEST_HEADERS {
+                let mut header = std::string::String::new();
+                reader.read_line(&mut header)?;
+                if !header.ends_with('\n') {
+                    break;
+                }
+                if header.trim_end().is_empty() {
+                    complete = true;
+                    break;
+                }
+            }
+        }
+        let path = path.map(|x| x.split('?').next().unwrap());
+        let file = match (method, path) {
+            (Some("GET") | Some("HEAD"), Some(path)) if complete => self.route(path),
+            _ => {
+                stream.write_all(b"HTTP/1.0 400 Bad Request\r\nContent-Length: 0\r\n\r\n")?;
+                // closing with unread bytes resets the connection, which may discard the response
+                stream.shutdown(Shutdown::Write)?;
+                let _ = std::io::copy(&mut (&stream).take(MAX_REQUEST_SIZE), &mut std::io::sink());
+                return Ok(());
+            }
+        };
+        // files are streamed, so that large debug files are not loaded into memory
+        let opened = file.and_then(|x| {
+            let file = std::fs::File::open(x).ok()?;
+            let size = file.metadata().ok()?.len();
+            Some((file, size))
+        });
+        match opened {
+            Some((mut file, size)) => {
+                let header = std::format!(
+                    "HTTP/1.0 200 OK\r\nContent-Type: application/octet-stream\r\nContent-Length: {}\r\n\r\n",
+                    size
+                );
+                stream.write_all(header.as_bytes())?;
+                if method == Some("GET") {
+                    std::io::copy(&mut file, &mut stream)?;
+                }
+            }
+            None => {
+                stream.write_all(b"HTTP/1.0 404 Not Found\r\nContent-Length: 0\r\n\r\n")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Device and inode numbers of the directory, which identify it across different paths.
+#[cfg(unix)]
+fn identity(directory: &Path) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    let metadata = std::fs::metadata(directory).ok()?;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn identity(_: &Path) -> Option<(u64, u64)> {
+    None
+}
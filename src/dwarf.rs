@@ -0,0 +1,657 @@
+use crate::context::*;
+use crate::context::{PropU16, PropU32, PropU64};
+use crate::utils::{read, terminate};
+use crate::Class;
+use core::marker::PhantomData;
+
+const DW_LNS_COPY: u8 = 1;
+const DW_LNS_ADVANCE_PC: u8 = 2;
+const DW_LNS_ADVANCE_LINE: u8 = 3;
+const DW_LNS_SET_FILE: u8 = 4;
+const DW_LNS_SET_COLUMN: u8 = 5;
+const DW_LNS_NEGATE_STMT: u8 = 6;
+const DW_LNS_CONST_ADD_PC: u8 = 8;
+const DW_LNS_FIXED_ADVANCE_PC: u8 = 9;
+
+const DW_LNE_END_SEQUENCE: u8 = 1;
+const DW_LNE_SET_ADDRESS: u8 = 2;
+
+const DW_LNCT_PATH: u64 = 1;
+const DW_LNCT_DIRECTORY_INDEX: u64 = 2;
+
+const DW_FORM_BLOCK2: u64 = 0x03;
+const DW_FORM_BLOCK4: u64 = 0x04;
+const DW_FORM_DATA2: u64 = 0x05;
+const DW_FORM_DATA4: u64 = 0x06;
+const DW_FORM_DATA8: u64 = 0x07;
+const DW_FORM_STRING: u64 = 0x08;
+const DW_FORM_BLOCK: u64 = 0x09;
+const DW_FORM_BLOCK1: u64 = 0x0a;
+const DW_FORM_DATA1: u64 = 0x0b;
+const DW_FORM_STRP: u64 = 0x0e;
+const DW_FORM_UDATA: u64 = 0x0f;
+const DW_FORM_DATA16: u64 = 0x1e;
+const DW_FORM_LINE_STRP: u64 = 0x1f;
+
+#[derive(Debug, Clone)]
+pub enum ParseLineProgramError {
+    BrokenHeader,
+    BadPropertyVersion,
+    BadPropertyLineRange,
+    BadPropertyForm,
+    BrokenDirectories,
+    BrokenFiles,
+    BrokenProgram,
+}
+
+/// Line number information, given by the ".debug_line" section.
+///
+/// Strings of DWARF 5 are found in ".debug_line_str" and ".debug_str", which are empty if they are not present.
+/// All sections should be decompressed already.
+#[derive(Debug, Clone, Copy)]
+pub struct DebugLine<'a, T: Context> {
+    content: &'a [u8],
+    line_str: &'a [u8],
+    str: &'a [u8],
+    _maker: PhantomData<T>,
+}
+
+impl<'a, T: Context> DebugLine<'a, T> {
+    pub fn new(content: &'a [u8], line_str: &'a [u8], str: &'a [u8]) -> Self {
+        Self {
+            content,
+            line_str,
+            str,
+            _maker: PhantomData,
+        }
+    }
+    /// Iterates over line number programs, stopping at the first broken one.
+    pub fn programs(
+        &self,
+    ) -> impl Iterator<Item = Result<LineProgram<'a, T>, ParseLineProgramError>> {
+        let debug_line = *self;
+        let mut offset = 0usize;
+        let mut done = false;
+        core::iter::from_fn(move || {
+            if done || offset >= debug_line.content.len() {
+                return None;
+            }
+            let result = LineProgram::parse(debug_line, offset);
+            match result {
+                Ok((_, next)) => offset = next,
+                Err(_) => done = true,
+            }
+            Some(result.map(|(program, _)| program))
+        })
+    }
+    /// Parses the line number program at the offset, which is "DW_AT_stmt_list" of a compilation unit.
+    pub fn program(&self, offset: usize) -> Result<LineProgram<'a, T>, ParseLineProgramError> {
+        LineProgram::parse(*self, offset).map(|(program, _)| program)
+    }
+    /// Finds the location of the address, which is a link-time address.
+    ///
+    /// All programs are decoded until the address is found, which costs O(programs × rows) for each address.
+    /// Callers with many addresses should select programs by address ranges of compilation units, such as ".debug_aranges", and call "DebugLine::program" and "LineProgram::find".
+    pub fn find(&self, address: u64) -> Result<Option<Location<'a>>, ParseLineProgramError> {
+        for program in self.programs() {
+            let program = program?;
+            if let Some(x) = program.find(address)? {
+                return Ok(Some(x));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Line number program of a compilation unit.
+#[derive(Debug, Clone, Copy)]
+pub struct LineProgram<'a, T: Context> {
+    debug_line: DebugLine<'a, T>,
+    version: u16,
+    wide: bool,
+    address_size: u8,
+    minimum_instruction_length: u8,
+    default_is_stmt: bool,
+    line_base: i8,
+    line_range: u8,
+    opcode_base: u8,
+    standard_opcode_lengths: &'a [u8],
+    directories: Table<'a>,
+    files: Table<'a>,
+    program: &'a [u8],
+}
+
+/// Directory or file name table, whose format is given by the header in DWARF 5.
+#[derive(Debug, Clone, Copy)]
+struct Table<'a> {
+    format: &'a [u8],
+    count: u64,
+    content: &'a [u8],
+}
+
+impl<'a, T: Context> LineProgram<'a, T> {
+    /// Returns the program and the offset of the next program.
+    fn parse(
+        debug_line: DebugLine<'a, T>,
+        offset: usize,
+    ) -> Result<(Self, usize), ParseLineProgramError> {
+        use ParseLineProgramError::*;
+        let content = debug_line.content;
+        let mut reader = Reader::<T>::new(content, offset);
+        let (length, wide) = match reader.u32().ok_or(BrokenHeader)? {
+            0xffffffff => (reader.u64().ok_or(BrokenHeader)?, true),
+            x if x >= 0xfffffff0 => return Err(BrokenHeader),
+            x => (x as u64, false),
+        };
+        let end = usize::try_from(length)
+            .ok()
+            .and_then(|x| x.checked_add(reader.offset))
+            .filter(|&x| x <= content.len())
+            .ok_or(BrokenHeader)?;
+        let mut reader = Reader::<T>::new(&content[..end], reader.offset);
+        let version = reader.u16().ok_or(BrokenHeader)?;
+        if !(2..=5).contains(&version) {
+            return Err(BadPropertyVersion);
+        }
+        let address_size = if version >= 5 {
+            let address_size = reader.u8().ok_or(BrokenHeader)?;
+            let _segment_selector_size = reader.u8().ok_or(BrokenHeader)?;
+            address_size
+        } else {
+            match T::CLASS {
+                Class::Class32 => 4,
+                Class::Class64 => 8,
+            }
+        };
+        let header_length = reader.offset_of(wide).ok_or(BrokenHeader)?;
+        let start = usize::try_from(header_length)
+            .ok()
+            .and_then(|x| x.checked_add(reader.offset))
+            .filter(|&x| x <= end)
+            .ok_or(BrokenHeader)?;
+        let minimum_instruction_length = reader.u8().ok_or(BrokenHeader)?;
+        if version >= 4 {
+            let _maximum_operations_per_instruction = reader.u8().ok_or(BrokenHeader)?;
+        }
+        let default_is_stmt = reader.u8().ok_or(BrokenHeader)? != 0;
+        let line_base = reader.u8().ok_or(BrokenHeader)? as i8;
+        let line_range = reader.u8().ok_or(BrokenHeader)?;
+        if line_range == 0 {
+            return Err(BadPropertyLineRange);
+        }
+        let opcode_base = reader.u8().ok_or(BrokenHeader)?;
+        let standard_opcode_lengths = reader
+            .bytes(opcode_base.saturating_sub(1) as usize)
+            .ok_or(BrokenHeader)?;
+        let mut program = Self {
+            debug_line,
+            version,
+            wide,
+            address_size,
+            minimum_instruction_length,
+            default_is_stmt,
+            line_base,
+            line_range,
+            opcode_base,
+            standard_opcode_lengths,
+            directories: Table {
+                format: &[],
+                count: 0,
+                content: &[],
+            },
+            files: Table {
+                format: &[],
+                count: 0,
+                content: &[],
+            },
+            program: &content[start..end],
+        };
+        let mut reader = Reader::<T>::new(&content[..start], reader.offset);
+        program.directories = program
+            .table(&mut reader, false)
+            .ok_or(BrokenDirectories)??;
+        program.files = program.table(&mut reader, true).ok_or(BrokenFiles)??;
+        Ok((program, end))
+    }
+    /// Reads a table, and checks that all entries can be parsed.
+    fn table(
+        &self,
+        reader: &mut Reader<'a, T>,
+        files: bool,
+    ) -> Option<Result<Table<'a>, ParseLineProgramError>> {
+        let data = reader.data;
+        let format = if self.version >= 5 {
+            let start = reader.offset;
+            let count = reader.u8()?;
+            for _ in 0..count {
+                let _content_type = reader.uleb()?;
+                let _form = reader.uleb()?;
+            }
+            &data[start + 1..reader.offset]
+        } else {
+            &[]
+        };
+        let start = reader.offset;
+        let mut table = Table {
+            format,
+            count: 0,
+            content: &[],
+        };
+        if self.version >= 5 {
+            table.count = reader.uleb()?;
+            let start = reader.offset;
+            for _ in 0..table.count {
+                if let Err(e) = self.entry(reader, format)? {
+                    return Some(Err(e));
+                }
+            }
+            table.content = &data[start..reader.offset];
+        } else {
+            // entries are terminated by an empty name
+            loop {
+                let name = reader.string()?;
+                if name.is_empty() {
+                    break;
+                }
+                if files {
+                    let _directory = reader.uleb()?;
+                    let _time = reader.uleb()?;
+                    let _size = reader.uleb()?;
+                }
+                table.count += 1;
+            }
+            table.content = &data[start..reader.offset];
+        }
+        Some(Ok(table))
+    }
+    /// Reads an entry of DWARF 5.
+    ///
+    /// Returns "None" if it's out of bounds.
+    fn entry(
+        &self,
+        reader: &mut Reader<'a, T>,
+        format: &'a [u8],
+    ) -> Option<Result<Entry<'a>, ParseLineProgramError>> {
+        use ParseLineProgramError::*;
+        let mut format = Reader::<T>::new(format, 0);
+        let mut path = None;
+        let mut directory = 0;
+        while !format.is_empty() {
+            let content_type = format.uleb()?;
+            let form = format.uleb()?;
+            let value = match self.value(reader, form) {
+                Ok(x) => x?,
+                Err(e) => return Some(Err(e)),
+            };
+            match (content_type, value) {
+                (DW_LNCT_PATH, Value::String(x)) => path = Some(x),
+                (DW_LNCT_DIRECTORY_INDEX, Value::Udata(x)) => directory = x,
+                (DW_LNCT_PATH | DW_LNCT_DIRECTORY_INDEX, _) => return Some(Err(BadPropertyForm)),
+                _ => (),
+            }
+        }
+        Some(Ok(Entry { path, directory }))
+    }
+    /// Reads a value, and returns "None" if it's out of bounds.
+    fn value(
+        &self,
+        reader: &mut Reader<'a, T>,
+        form: u64,
+    ) -> Result<Option<Value<'a>>, ParseLineProgramError> {
+        let string = |strings: &'a [u8], offset: Option<u64>| {
+            let offset = usize::try_from(offset?).ok()?;
+            Some(Value::String(terminate(strings.get(offset..)?)?))
+        };
+        let value = match form {
+            DW_FORM_STRING => reader.string().map(Value::String),
+            DW_FORM_LINE_STRP => string(self.debug_line.line_str, reader.offset_of(self.wide)),
+            DW_FORM_STRP => string(self.debug_line.str, reader.offset_of(self.wide)),
+            DW_FORM_UDATA => reader.uleb().map(Value::Udata),
+            DW_FORM_DATA1 => reader.u8().map(|x| Value::Udata(x as u64)),
+            DW_FORM_DATA2 => reader.u16().map(|x| Value::Udata(x as u64)),
+            DW_FORM_DATA4 => reader.u32().map(|x| Value::Udata(x as u64)),
+            DW_FORM_DATA8 => reader.u64().map(Value::Udata),
+            DW_FORM_DATA16 => reader.bytes(16).map(|_| Value::Other),
+            DW_FORM_BLOCK => {
+                let n = reader.uleb().and_then(|x| usize::try_from(x).ok());
+                n.and_then(|n| reader.bytes(n)).map(|_| Value::Other)
+            }
+            DW_FORM_BLOCK1 | DW_FORM_BLOCK2 | DW_FORM_BLOCK4 => {
+                let n = match form {
+                    DW_FORM_BLOCK1 => reader.u8().map(|x| x as usize),
+                    DW_FORM_BLOCK2 => reader.u16().map(|x| x as usize),
+                    _ => reader.u32().and_then(|x| usize::try_from(x).ok()),
+                };
+                n.and_then(|n| reader.bytes(n)).map(|_| Value::Other)
+            }
+            _ => return Err(ParseLineProgramError::BadPropertyForm),
+        };
+        Ok(value)
+    }
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+    pub fn address_size(&self) -> u8 {
+        self.address_size
+    }
+    /// Number of directories, including the compilation directory in DWARF 5.
+    pub fn directories(&self) -> u64 {
+        self.directories.count
+    }
+    /// Number of files, including the primary source file in DWARF 5.
+    pub fn files(&self) -> u64 {
+        self.files.count
+    }
+    /// Returns the directory. Index zero is the compilation directory, which is not present before DWARF 5.
+    pub fn directory(&self, index: u64) -> Option<&'a [u8]> {
+        let mut reader = Reader::<T>::new(self.directories.content, 0);
+        if self.version >= 5 {
+            for i in 0..self.directories.count {
+                let entry = self.entry(&mut reader, self.directories.format)?.ok()?;
+                if i == index {
+                    return entry.path;
+                }
+            }
+            None
+        } else {
+            // "include_directories" starts from index one
+            let index = index.checked_sub(1)?;
+            for _ in 0..index {
+                reader.string()?;
+            }
+            reader.string().filter(|x| !x.is_empty())
+        }
+    }
+    /// Returns the file. Files start from index one before DWARF 5, and from index zero since DWARF 5.
+    pub fn file(&self, index: u64) -> Option<LineFile<'a>> {
+        let mut reader = Reader::<T>::new(self.files.content, 0);
+        if self.version >= 5 {
+            for i in 0..self.files.count {
+                let entry = self.entry(&mut reader, self.files.format)?.ok()?;
+                if i == index {
+                    return Some(LineFile {
+                        directory: self.directory(entry.directory),
+                        name: entry.path?,
+                    });
+                }
+            }
+            None
+        } else {
+            let index = index.checked_sub(1)?;
+            for i in 0..self.files.count {
+                let name = reader.string()?;
+                let directory = reader.uleb()?;
+                let _time = reader.uleb()?;
+                let _size = reader.uleb()?;
+                if i == index {
+                    return Some(LineFile {
+                        directory: self.directory(directory),
+                        name,
+                    });
+                }
+            }
+            None
+        }
+    }
+    /// Iterates over rows of the line number matrix, stopping at the first broken instruction.
+    pub fn rows(&self) -> impl Iterator<Item = Result<LineRow, ParseLineProgramError>> + 'a {
+        let program = *self;
+        let mut reader = Reader::<T>::new(self.program, 0);
+        let mut state = LineRow::new(self.default_is_stmt);
+        let mut done = false;
+        core::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            match program.step(&mut reader, &mut state) {
+                Ok(Some(row)) => Some(Ok(row)),
+                Ok(None) => {
+                    done = true;
+                    None
+                }
+                Err(e) => {
+                    done = true;
+                    Some(Err(e))
+                }
+            }
+        })
+    }
+    /// Runs instructions until a row is appended, or the program ends.
+    fn step(
+        &self,
+        reader: &mut Reader<'a, T>,
+        state: &mut LineRow,
+    ) -> Result<Option<LineRow>, ParseLineProgramError> {
+        use ParseLineProgramError::*;
+        let minimum_instruction_length = self.minimum_instruction_length as u64;
+        while !reader.is_empty() {
+            let opcode = reader.u8().ok_or(BrokenProgram)?;
+            if opcode >= self.opcode_base {
+                let adjusted = opcode - self.opcode_base;
+                let advance = (adjusted / self.line_range) as u64 * minimum_instruction_length;
+                let line = self.line_base as i64 + (adjusted % self.line_range) as i64;
+                state.address = state.address.wrapping_add(advance);
+                state.line = state.line.wrapping_add(line as u64);
+                return Ok(Some(*state));
+            }
+            match opcode {
+                0 => {
+                    let length = reader.uleb().ok_or(BrokenProgram)?;
+                    let length = usize::try_from(length).map_err(|_| BrokenProgram)?;
+                    let instruction = reader.bytes(length).ok_or(BrokenProgram)?;
+                    let mut instruction = Reader::<T>::new(instruction, 0);
+                    match instruction.u8() {
+                        Some(DW_LNE_END_SEQUENCE) => {
+                            state.end_sequence = true;
+                            let row = *state;
+                            *state = LineRow::new(self.default_is_stmt);
+                            return Ok(Some(row));
+                        }
+                        Some(DW_LNE_SET_ADDRESS) => {
+                            state.address = match length - 1 {
+                                4 => instruction.u32().map(|x| x as u64),
+                                8 => instruction.u64(),
+                                _ => None,
+                            }
+                            .ok_or(BrokenProgram)?;
+                        }
+                        Some(_) => (),
+                        None => return Err(BrokenProgram),
+                    }
+                }
+                DW_LNS_COPY => return Ok(Some(*state)),
+                DW_LNS_ADVANCE_PC => {
+                    let advance = reader.uleb().ok_or(BrokenProgram)?;
+                    let advance = advance.wrapping_mul(minimum_instruction_length);
+                    state.address = state.address.wrapping_add(advance);
+                }
+                DW_LNS_ADVANCE_LINE => {
+                    let advance = reader.sleb().ok_or(BrokenProgram)?;
+                    state.line = state.line.wrapping_add(advance as u64);
+                }
+                DW_LNS_SET_FILE => state.file = reader.uleb().ok_or(BrokenProgram)?,
+                DW_LNS_SET_COLUMN => state.column = reader.uleb().ok_or(BrokenProgram)?,
+                DW_LNS_NEGATE_STMT => state.is_stmt = !state.is_stmt,
+                DW_LNS_CONST_ADD_PC => {
+                    let adjusted = 255 - self.opcode_base;
+                    let advance = (adjusted / self.line_range) as u64 * minimum_instruction_length;
+                    state.address = state.address.wrapping_add(advance);
+                }
+                DW_LNS_FIXED_ADVANCE_PC => {
+                    let advance = reader.u16().ok_or(BrokenProgram)?;
+                    state.address = state.address.wrapping_add(advance as u64);
+                }
+                x => {
+                    // other standard opcodes only change states which are not tracked
+                    let n = self.standard_opcode_lengths[x as usize - 1];
+                    for _ in 0..n {
+                        reader.uleb().ok_or(BrokenProgram)?;
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+    /// Finds the location of the address in sequences of the program.
+    pub fn find(&self, address: u64) -> Result<Option<Location<'a>>, ParseLineProgramError> {
+        let mut previous: Option<LineRow> = None;
+        for row in self.rows() {
+            let row = row?;
+            if let Some(x) = previous {
+                if x.address <= address && address < row.address {
+                    return Ok(Some(Location {
+                        file: self.file(x.file),
+                        line: x.line,
+                        column: x.column,
+                    }));
+                }
+            }
+            previous = (!row.end_sequence).then_some(row);
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry<'a> {
+    path: Option<&'a [u8]>,
+    directory: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value<'a> {
+    String(&'a [u8]),
+    Udata(u64),
+    /// Such as "MD5" of files, which is not used.
+    Other,
+}
+
+/// Row of the line number matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRow {
+    pub address: u64,
+    pub file: u64,
+    pub line: u64,
+    /// Column, which is zero if it's unknown.
+    pub column: u64,
+    pub is_stmt: bool,
+    /// The row is the first address after the end of a sequence.
+    pub end_sequence: bool,
+}
+
+impl LineRow {
+    fn new(default_is_stmt: bool) -> Self {
+        Self {
+            address: 0,
+            file: 1,
+            line: 1,
+            column: 0,
+            is_stmt: default_is_stmt,
+            end_sequence: false,
+        }
+    }
+}
+
+/// Source file in a line number program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineFile<'a> {
+    /// Directory, which is "None" for the compilation directory before DWARF 5.
+    pub directory: Option<&'a [u8]>,
+    /// Name, which may be an absolute path.
+    pub name: &'a [u8],
+}
+
+/// Source location of an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location<'a> {
+    pub file: Option<LineFile<'a>>,
+    pub line: u64,
+    pub column: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Reader<'a, T: Context> {
+    data: &'a [u8],
+    offset: usize,
+    _maker: PhantomData<T>,
+}
+
+impl<'a, T: Context> Reader<'a, T> {
+    fn new(data: &'a [u8], offset: usize) -> Self {
+        Self {
+            data,
+            offset,
+            _maker: PhantomData,
+        }
+    }
+    fn is_empty(&self) -> bool {
+        self.offset >= self.data.len()
+    }
+    fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(n)?;
+        let bytes = self.data.get(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.bytes(1)?[0])
+    }
+    fn u16(&mut self) -> Option<u16> {
+        let x = T::interpret(*read::<PropU16>(self.data, self.offset)?);
+        self.offset += 2;
+        Some(x)
+    }
+    fn u32(&mut self) -> Option<u32> {
+        let x = T::interpret(*read::<PropU32>(self.data, self.offset)?);
+        self.offset += 4;
+        Some(x)
+    }
+    fn u64(&mut self) -> Option<u64> {
+        let x = T::interpret(*read::<PropU64>(self.data, self.offset)?);
+        self.offset += 8;
+        Some(x)
+    }
+    /// Reads a section offset, which is 8 bytes in the 64-bit DWARF format.
+    fn offset_of(&mut self, wide: bool) -> Option<u64> {
+        match wide {
+            true => self.u64(),
+            false => self.u32().map(|x| x as u64),
+        }
+    }
+    fn uleb(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.u8()?;
+            if shift < 64 {
+                value |= ((byte & 0x7f) as u64) << shift;
+            }
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+    }
+    fn sleb(&mut self) -> Option<i64> {
+        let mut value = 0i64;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.u8()?;
+            if shift < 64 {
+                value |= ((byte & 0x7f) as i64) << shift;
+            }
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    value |= -1i64 << shift;
+                }
+                return Some(value);
+            }
+        }
+    }
+    fn string(&mut self) -> Option<&'a [u8]> {
+        let string = terminate(self.data.get(self.offset..)?)?;
+        self.offset += string.len() + 1;
+        Some(string)
+    }
+}
@@ -21,6 +21,13 @@ impl<'a, T: Context> Dynamic<'a, T> {
     pub fn entries(&self) -> &'a [DynamicEntry<T>] {
         self.entries
     }
+    /// Iterates over entries until the first "DT_NULL".
+    pub fn iter(&self) -> impl Iterator<Item = &'a DynamicEntry<T>> {
+        let null: T::Integer = 0u32.into();
+        self.entries
+            .iter()
+            .take_while(move |x| T::interpret(x.tag) != null)
+    }
 }
 
 #[repr(C)]
@@ -37,6 +44,13 @@ impl<T: Context> DynamicEntry<T> {
     pub fn un(&self) -> T::Integer {
         T::interpret(self.un)
     }
+    /// Returns the tag widened to "DynamicTag64", or "None" if it's non-standard.
+    pub fn checked_tag(&self) -> Option<DynamicTag64> {
+        match self.tag().into() {
+            DynamicTag64::NonStandard(_) => None,
+            x => Some(x),
+        }
+    }
 }
 
 unsafe impl<T: Context> Pod for DynamicEntry<T> {}
@@ -120,7 +134,8 @@ impl From<u32> for DynamicTag32 {
             32 => PreInitArray,
             33 => PreInitArraySize,
             34 => SymtabShndx,
-            x @ 0x6000000D..=0x6FFFF000 => OsSpecific(x),
+            // GNU extensions, such as "DT_GNU_HASH" and "DT_VERSYM", are beyond "DT_HIOS"
+            x @ 0x6000000D..=0x6FFFFFFF => OsSpecific(x),
             x @ 0x70000000..=0x7FFFFFFF => ProcessorSpecific(x),
             x => NonStandard(x),
         }
@@ -172,6 +187,12 @@ impl From<DynamicTag32> for u32 {
     }
 }
 
+impl From<DynamicTag32> for DynamicTag64 {
+    fn from(value: DynamicTag32) -> Self {
+        u64::from(u32::from(value)).into()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum DynamicTag64 {
     Null,
@@ -251,7 +272,8 @@ impl From<u64> for DynamicTag64 {
             32 => PreInitArray,
             33 => PreInitArraySize,
             34 => SymtabShndx,
-            x @ 0x6000000D..=0x6FFFF000 => OsSpecific(x),
+            // GNU extensions, such as "DT_GNU_HASH" and "DT_VERSYM", are beyond "DT_HIOS"
+            x @ 0x6000000D..=0x6FFFFFFF => OsSpecific(x),
             x @ 0x70000000..=0x7FFFFFFF => ProcessorSpecific(x),
             x => NonStandard(x),
         }
@@ -324,3 +346,42 @@ impl DynamicFlags64 {
     pub const BIND_NOW: Self = Self(0x8);
     pub const STATIC_TLS: Self = Self(0x10);
 }
+
+/// Address of the GNU hash table.
+pub const DYNAMIC_TAG_GNU_HASH: DynamicTag64 = DynamicTag64::OsSpecific(0x6ffffef5);
+/// Address of the version symbol table.
+pub const DYNAMIC_TAG_VERSYM: DynamicTag64 = DynamicTag64::OsSpecific(0x6ffffff0);
+/// State flags.
+pub const DYNAMIC_TAG_FLAGS_1: DynamicTag64 = DynamicTag64::OsSpecific(0x6ffffffb);
+/// Address of the version definition table.
+pub const DYNAMIC_TAG_VERDEF: DynamicTag64 = DynamicTag64::OsSpecific(0x6ffffffc);
+/// Number of version definitions.
+pub const DYNAMIC_TAG_VERDEFNUM: DynamicTag64 = DynamicTag64::OsSpecific(0x6ffffffd);
+/// Address of the version dependency table.
+pub const DYNAMIC_TAG_VERNEED: DynamicTag64 = DynamicTag64::OsSpecific(0x6ffffffe);
+/// Number of version dependencies.
+pub const DYNAMIC_TAG_VERNEEDNUM: DynamicTag64 = DynamicTag64::OsSpecific(0x6fffffff);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, From, Into, BitAnd, BitOr, BitXor, LowerHex)]
+pub struct DynamicFlags1(pub u32);
+
+impl DynamicFlags1 {
+    pub const NOW: Self = Self(0x1);
+    pub const GLOBAL: Self = Self(0x2);
+    pub const GROUP: Self = Self(0x4);
+    pub const NODELETE: Self = Self(0x8);
+    pub const LOADFLTR: Self = Self(0x10);
+    pub const INITFIRST: Self = Self(0x20);
+    pub const NOOPEN: Self = Self(0x40);
+    pub const ORIGIN: Self = Self(0x80);
+    pub const DIRECT: Self = Self(0x100);
+    pub const INTERPOSE: Self = Self(0x400);
+    pub const NODEFLIB: Self = Self(0x800);
+    pub const NODUMP: Self = Self(0x1000);
+    pub const CONFALT: Self = Self(0x2000);
+    pub const ENDFILTEE: Self = Self(0x4000);
+    pub const DISPRELDNE: Self = Self(0x8000);
+    pub const DISPRELPND: Self = Self(0x10000);
+    pub const NODIRECT: Self = Self(0x20000);
+    pub const PIE: Self = Self(0x8000000);
+}
@@ -0,0 +1,377 @@
+use crate::context::*;
+use crate::dynamic::{Dynamic, DynamicEntry, DynamicFlags1, DynamicTag64, ParseDynamicError};
+use crate::dynamic::{DYNAMIC_TAG_FLAGS_1, DYNAMIC_TAG_GNU_HASH, DYNAMIC_TAG_VERDEF};
+use crate::dynamic::{DYNAMIC_TAG_VERNEED, DYNAMIC_TAG_VERSYM};
+use crate::elf::Variant;
+use crate::gnu_hash::{GnuHash, ParseGnuHashError};
+use crate::hash::{Hash, ParseHashError};
+use crate::program::{ParseProgramsError, ProgramType, Programs, VaddrContent};
+use crate::rel::{ParseRelError, Rel, RelEntry};
+use crate::rela::{ParseRelaError, Rela, RelaEntry};
+use crate::strtab::{ParseStrtabError, Strtab};
+use crate::symbols::{ParseSymbolsError, Symbols};
+use crate::symtab::{ParseSymtabError, SymbolBinding, Symtab, SymtabEntry};
+use crate::version::{ParseVerdefError, ParseVerneedError, ParseVersymError};
+use crate::version::{Verdef, Verneed, Versions, Versym, VersymEntry};
+
+#[derive(Debug, Clone)]
+pub enum ParseDynamicInfoError {
+    FromPrograms(ParseProgramsError),
+    BrokenDynamic,
+    FromDynamic(ParseDynamicError),
+    BadPropertyStrtab,
+    FromStrtab(ParseStrtabError),
+    BadStringEntry,
+}
+
+#[derive(Debug, Clone)]
+pub enum ParseDynamicViewError {
+    FromPrograms(ParseProgramsError),
+    FromDynamicInfo(ParseDynamicInfoError),
+    BadPropertyHash,
+    FromHash(ParseHashError),
+    BadPropertyGnuHash,
+    FromGnuHash(ParseGnuHashError),
+    BadPropertySymtab,
+    BadPropertySyment,
+    FromSymtab(ParseSymtabError),
+    FromSymbols(ParseSymbolsError),
+    BadPropertyRela,
+    BadPropertyRelaent,
+    FromRela(ParseRelaError),
+    BadPropertyRel,
+    BadPropertyRelent,
+    FromRel(ParseRelError),
+    BadPropertyJmprel,
+    BadPropertyPltrel,
+    BadPropertyVersym,
+    FromVersym(ParseVersymError),
+    BadPropertyVerdef,
+    FromVerdef(ParseVerdefError),
+    BadPropertyVerneed,
+    FromVerneed(ParseVerneedError),
+}
+
+/// Dynamic program, joined with its string table.
+///
+/// The string table is given by "DT_STRTAB" and "DT_STRSZ", which are resolved through load programs.
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicInfo<'a, T: Context> {
+    dynamic: Dynamic<'a, T>,
+    strtab: Option<Strtab<'a>>,
+}
+
+impl<'a, T: Context> DynamicInfo<'a, T> {
+    /// Returns "None" if there is no dynamic program.
+    pub fn parse(elf: Variant<'a, T>) -> Result<Option<Self>, ParseDynamicInfoError> {
+        use ParseDynamicInfoError::*;
+        let programs = match Programs::parse(elf).map_err(FromPrograms)? {
+            Some(programs) => programs,
+            None => return Ok(None),
+        };
+        for (_, program) in programs.iter() {
+            match program {
+                Ok(x) if x.header().typa() == ProgramType::Dynamic => {
+                    let dynamic = Dynamic::parse(x.content()).map_err(FromDynamic)?;
+                    return Self::new(dynamic, programs).map(Some);
+                }
+                Ok(_) => (),
+                Err(_) => return Err(BrokenDynamic),
+            }
+        }
+        Ok(None)
+    }
+    pub fn new(
+        dynamic: Dynamic<'a, T>,
+        programs: Programs<'a, T>,
+    ) -> Result<Self, ParseDynamicInfoError> {
+        use ParseDynamicInfoError::*;
+        let find = |tag| {
+            dynamic
+                .iter()
+                .find(|x| x.checked_tag() == Some(tag))
+                .map(|x| x.un().into())
+        };
+        let strtab = match (find(DynamicTag64::Strtab), find(DynamicTag64::StrSize)) {
+            (Some(address), Some(size)) => {
+                let size = usize::try_from(size).map_err(|_| BadPropertyStrtab)?;
+                let content = match programs.read_at_vaddr(address, size) {
+                    Some(VaddrContent::File(x)) => x,
+                    _ => return Err(BadPropertyStrtab),
+                };
+                Some(Strtab::parse(content).map_err(FromStrtab)?)
+            }
+            (None, None) => None,
+            _ => return Err(BadPropertyStrtab),
+        };
+        let info = Self { dynamic, strtab };
+        for x in dynamic.iter() {
+            if matches!(
+                x.checked_tag(),
+                Some(
+                    DynamicTag64::Needed
+                        | DynamicTag64::SoName
+                        | DynamicTag64::RPath
+                        | DynamicTag64::RunPath
+                )
+            ) && info.string(x).is_none()
+            {
+                return Err(BadStringEntry);
+            }
+        }
+        Ok(info)
+    }
+    pub fn dynamic(&self) -> Dynamic<'a, T> {
+        self.dynamic
+    }
+    pub fn strtab(&self) -> Option<Strtab<'a>> {
+        self.strtab
+    }
+    /// Iterates over names of needed libraries.
+    pub fn needed(&self) -> impl Iterator<Item = &'a [u8]> {
+        let info = *self;
+        self.dynamic
+            .iter()
+            .filter(|x| x.checked_tag() == Some(DynamicTag64::Needed))
+            .map(move |x| info.string(x).unwrap())
+    }
+    pub fn soname(&self) -> Option<&'a [u8]> {
+        self.find_string(DynamicTag64::SoName)
+    }
+    /// Library search paths separated by colons, which are deprecated in favor of "runpath".
+    pub fn rpath(&self) -> Option<&'a [u8]> {
+        self.find_string(DynamicTag64::RPath)
+    }
+    /// Library search paths separated by colons.
+    pub fn runpath(&self) -> Option<&'a [u8]> {
+        self.find_string(DynamicTag64::RunPath)
+    }
+    pub fn flags(&self) -> Option<T::DynamicFlags> {
+        self.find(DynamicTag64::Flags).map(T::DynamicFlags::from)
+    }
+    pub fn flags_1(&self) -> Option<DynamicFlags1> {
+        let value: u64 = self.find(DYNAMIC_TAG_FLAGS_1)?.into();
+        Some(DynamicFlags1(value as u32))
+    }
+    /// Address of the initialization function.
+    pub fn init(&self) -> Option<T::Integer> {
+        self.find(DynamicTag64::Init)
+    }
+    /// Address of the termination function.
+    pub fn fini(&self) -> Option<T::Integer> {
+        self.find(DynamicTag64::Fini)
+    }
+    /// Address and size in bytes of the array of initialization functions.
+    pub fn init_array(&self) -> Option<(T::Integer, T::Integer)> {
+        Some((
+            self.find(DynamicTag64::InitArray)?,
+            self.find(DynamicTag64::InitArraySize)?,
+        ))
+    }
+    /// Address and size in bytes of the array of termination functions.
+    pub fn fini_array(&self) -> Option<(T::Integer, T::Integer)> {
+        Some((
+            self.find(DynamicTag64::FiniArray)?,
+            self.find(DynamicTag64::FiniArraySize)?,
+        ))
+    }
+    /// Address and size in bytes of the array of pre-initialization functions.
+    pub fn preinit_array(&self) -> Option<(T::Integer, T::Integer)> {
+        Some((
+            self.find(DynamicTag64::PreInitArray)?,
+            self.find(DynamicTag64::PreInitArraySize)?,
+        ))
+    }
+    /// Finds the value of the first entry of the tag.
+    pub fn find(&self, tag: DynamicTag64) -> Option<T::Integer> {
+        self.dynamic
+            .iter()
+            .find(|x| x.checked_tag() == Some(tag))
+            .map(|x| x.un())
+    }
+    fn find_string(&self, tag: DynamicTag64) -> Option<&'a [u8]> {
+        let entry = self.dynamic.iter().find(|x| x.checked_tag() == Some(tag))?;
+        Some(self.string(entry).unwrap())
+    }
+    fn string(&self, entry: &DynamicEntry<T>) -> Option<&'a [u8]> {
+        let offset: u64 = entry.un().into();
+        self.strtab?.find(usize::try_from(offset).ok()?)
+    }
+}
+
+/// Dynamic linking tables found by addresses in the dynamic program, without section headers.
+///
+/// The number of dynamic symbols is given by "DT_HASH", or "DT_GNU_HASH" if it's absent.
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicView<'a, T: Context> {
+    info: DynamicInfo<'a, T>,
+    hash: Option<Hash<'a, T>>,
+    gnu_hash: Option<GnuHash<'a, T>>,
+    symbols: Option<Symbols<'a, T>>,
+    rela: Option<Rela<'a, T>>,
+    rel: Option<Rel<'a, T>>,
+    plt: Option<PltRelocations<'a, T>>,
+    versions: Option<Versions<'a, T>>,
+}
+
+impl<'a, T: Context> DynamicView<'a, T> {
+    /// Returns "None" if there is no dynamic program.
+    pub fn parse(elf: Variant<'a, T>) -> Result<Option<Self>, ParseDynamicViewError> {
+        use ParseDynamicViewError::*;
+        let programs = match Programs::parse(elf).map_err(FromPrograms)? {
+            Some(programs) => programs,
+            None => return Ok(None),
+        };
+        match DynamicInfo::parse(elf).map_err(FromDynamicInfo)? {
+            Some(info) => Self::new(info, programs).map(Some),
+            None => Ok(None),
+        }
+    }
+    pub fn new(
+        info: DynamicInfo<'a, T>,
+        programs: Programs<'a, T>,
+    ) -> Result<Self, ParseDynamicViewError> {
+        use ParseDynamicViewError::*;
+        let find = |tag| info.find(tag).map(Into::<u64>::into);
+        let content = |address| programs.content_at_vaddr(address);
+        let read = |address, size: u64| match programs
+            .read_at_vaddr(address, usize::try_from(size).ok()?)?
+        {
+            VaddrContent::File(x) => Some(x),
+            VaddrContent::Bss => None,
+        };
+        let hash = find(DynamicTag64::Hash)
+            .map(|x| Hash::parse(content(x).ok_or(BadPropertyHash)?).map_err(FromHash))
+            .transpose()?;
+        let gnu_hash = find(DYNAMIC_TAG_GNU_HASH)
+            .map(|x| GnuHash::parse(content(x).ok_or(BadPropertyGnuHash)?).map_err(FromGnuHash))
+            .transpose()?;
+        let num = match (hash, gnu_hash) {
+            (Some(hash), _) => Some(hash.chains().len()),
+            (None, Some(gnu_hash)) => Some(gnu_hash.symbols_num().ok_or(BadPropertyGnuHash)?),
+            (None, None) => None,
+        };
+        let symtab = match (find(DynamicTag64::Symtab), num, info.strtab()) {
+            (Some(address), Some(num), Some(strtab)) => {
+                let entsize = core::mem::size_of::<SymtabEntry<T>>();
+                if find(DynamicTag64::SymEnt).is_some_and(|x| x != entsize as u64) {
+                    return Err(BadPropertySyment);
+                }
+                let size = entsize.checked_mul(num).ok_or(BadPropertySymtab)?;
+                let content = read(address, size as u64).ok_or(BadPropertySymtab)?;
+                Some((Symtab::parse(content).map_err(FromSymtab)?, strtab))
+            }
+            _ => None,
+        };
+        let symbols = match symtab {
+            Some((symtab, strtab)) => {
+                // "sh_info" is not available, so it's computed as the index of the first non-local symbol
+                let info = symtab
+                    .iter()
+                    .find(|(_, x)| x.checked_binding() != Some(SymbolBinding::Local))
+                    .map_or(symtab.entries().len(), |(index, _)| index);
+                Some(Symbols::new(symtab, strtab, None, info as u32).map_err(FromSymbols)?)
+            }
+            None => None,
+        };
+        let rela = match (find(DynamicTag64::Rela), find(DynamicTag64::RelaSize)) {
+            (Some(address), Some(size)) => {
+                let entsize = core::mem::size_of::<RelaEntry<T>>() as u64;
+                if find(DynamicTag64::RelaEnt).is_some_and(|x| x != entsize) {
+                    return Err(BadPropertyRelaent);
+                }
+                let content = read(address, size).ok_or(BadPropertyRela)?;
+                Some(Rela::parse(content).map_err(FromRela)?)
+            }
+            _ => None,
+        };
+        let rel = match (find(DynamicTag64::Rel), find(DynamicTag64::RelSize)) {
+            (Some(address), Some(size)) => {
+                let entsize = core::mem::size_of::<RelEntry<T>>() as u64;
+                if find(DynamicTag64::RelEnt).is_some_and(|x| x != entsize) {
+                    return Err(BadPropertyRelent);
+                }
+                let content = read(address, size).ok_or(BadPropertyRel)?;
+                Some(Rel::parse(content).map_err(FromRel)?)
+            }
+            _ => None,
+        };
+        let plt = match (find(DynamicTag64::JmpRel), find(DynamicTag64::PltRelSize)) {
+            (Some(address), Some(size)) => {
+                let content = read(address, size).ok_or(BadPropertyJmprel)?;
+                match find(DynamicTag64::PltRel).map(DynamicTag64::from) {
+                    Some(DynamicTag64::Rela) => Some(PltRelocations::Rela(
+                        Rela::parse(content).map_err(FromRela)?,
+                    )),
+                    Some(DynamicTag64::Rel) => {
+                        Some(PltRelocations::Rel(Rel::parse(content).map_err(FromRel)?))
+                    }
+                    _ => return Err(BadPropertyPltrel),
+                }
+            }
+            _ => None,
+        };
+        let versions = match (find(DYNAMIC_TAG_VERSYM), symbols) {
+            (Some(address), Some(symbols)) => {
+                let size = core::mem::size_of::<VersymEntry<T>>() * symbols.num();
+                let versym = read(address, size as u64).ok_or(BadPropertyVersym)?;
+                let versym = Versym::parse(versym).map_err(FromVersym)?;
+                let verdef = find(DYNAMIC_TAG_VERDEF)
+                    .map(|x| {
+                        Verdef::parse(content(x).ok_or(BadPropertyVerdef)?).map_err(FromVerdef)
+                    })
+                    .transpose()?;
+                let verneed = find(DYNAMIC_TAG_VERNEED)
+                    .map(|x| {
+                        Verneed::parse(content(x).ok_or(BadPropertyVerneed)?).map_err(FromVerneed)
+                    })
+                    .transpose()?;
+                Some(Versions::new(versym, verdef, verneed, symbols.strtab()))
+            }
+            _ => None,
+        };
+        Ok(Self {
+            info,
+            hash,
+            gnu_hash,
+            symbols,
+            rela,
+            rel,
+            plt,
+            versions,
+        })
+    }
+    pub fn info(&self) -> DynamicInfo<'a, T> {
+        self.info
+    }
+    pub fn hash(&self) -> Option<Hash<'a, T>> {
+        self.hash
+    }
+    pub fn gnu_hash(&self) -> Option<GnuHash<'a, T>> {
+        self.gnu_hash
+    }
+    /// Dynamic symbols, which are absent if there is no hash table.
+    pub fn symbols(&self) -> Option<Symbols<'a, T>> {
+        self.symbols
+    }
+    pub fn rela(&self) -> Option<Rela<'a, T>> {
+        self.rela
+    }
+    pub fn rel(&self) -> Option<Rel<'a, T>> {
+        self.rel
+    }
+    /// Relocations of the procedure linkage table.
+    pub fn plt(&self) -> Option<PltRelocations<'a, T>> {
+        self.plt
+    }
+    pub fn versions(&self) -> Option<Versions<'a, T>> {
+        self.versions
+    }
+}
+
+/// Relocations of the procedure linkage table, whose type is given by "DT_PLTREL".
+#[derive(Debug, Clone, Copy)]
+pub enum PltRelocations<'a, T: Context> {
+    Rel(Rel<'a, T>),
+    Rela(Rela<'a, T>),
+}
@@ -63,6 +63,14 @@ impl<'a, T: Context> Variant<'a, T> {
             header: eheader,
         })
     }
+    /// Returns "None" if its identification does not match the context, or the header is broken.
+    pub fn checked_parse(data: &'a [u8]) -> Option<Self> {
+        let ident = Ident::parse(data).ok()?;
+        if ident.class() != T::CLASS || ident.data() != T::DATA {
+            return None;
+        }
+        Self::parse(data).ok()
+    }
     pub fn data(&self) -> &'a [u8] {
         self.data
     }
@@ -548,3 +556,9 @@ pub const ELF_MACHINE_MOXIE: u16 = 223;
 pub const ELF_MACHINE_AMDGPU: u16 = 224;
 /// RISC-V.
 pub const ELF_MACHINE_RISCV: u16 = 243;
+/// Linux BPF -- in-kernel virtual machine.
+pub const ELF_MACHINE_BPF: u16 = 247;
+/// C-SKY.
+pub const ELF_MACHINE_CSKY: u16 = 252;
+/// LoongArch.
+pub const ELF_MACHINE_LOONGARCH: u16 = 258;
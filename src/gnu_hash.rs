@@ -0,0 +1,216 @@
+use crate::context::PropU32;
+use crate::context::*;
+use crate::hash::{HashBucketEntry, HashChainEntry};
+use crate::strtab::Strtab;
+use crate::symtab::{Symtab, SymtabEntry};
+use crate::utils::{read, read_n, read_s, Pod};
+use crate::version::Versions;
+use crate::Class;
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub enum ParseGnuHashError {
+    BrokenHeader,
+    BadPropertyBloomShift,
+    BrokenBloom,
+    BrokenBuckets,
+    BrokenChains,
+}
+
+/// GNU hash section.
+#[derive(Debug, Clone, Copy)]
+pub struct GnuHash<'a, T: Context> {
+    header: &'a GnuHashHeader<T>,
+    bloom: &'a [GnuHashBloomEntry<T>],
+    buckets: &'a [HashBucketEntry<T>],
+    chains: &'a [HashChainEntry<T>],
+}
+
+impl<'a, T: Context> GnuHash<'a, T> {
+    pub fn parse(content: &'a [u8]) -> Result<Self, ParseGnuHashError> {
+        use ParseGnuHashError::*;
+        let mut offset = 0usize;
+        let header: &GnuHashHeader<T> = read(content, offset).ok_or(BrokenHeader)?;
+        // the shift applies to a 32-bit hash value
+        if header.bloom_shift() >= 32 {
+            return Err(BadPropertyBloomShift);
+        }
+        offset += core::mem::size_of::<GnuHashHeader<T>>();
+        let bloom: &[GnuHashBloomEntry<T>] =
+            read_n(content, offset, header.bloom_size() as usize).ok_or(BrokenBloom)?;
+        offset += core::mem::size_of::<GnuHashBloomEntry<T>>() * header.bloom_size() as usize;
+        let buckets: &[HashBucketEntry<T>] =
+            read_n(content, offset, header.nbuckets() as usize).ok_or(BrokenBuckets)?;
+        offset += core::mem::size_of::<HashBucketEntry<T>>() * header.nbuckets() as usize;
+        let chains: &[HashChainEntry<T>] = read_s(&content[offset..]).ok_or(BrokenChains)?;
+        Ok(Self {
+            header,
+            bloom,
+            buckets,
+            chains,
+        })
+    }
+    pub fn header(&self) -> &'a GnuHashHeader<T> {
+        self.header
+    }
+    pub fn bloom(&self) -> &'a [GnuHashBloomEntry<T>] {
+        self.bloom
+    }
+    pub fn buckets(&self) -> &'a [HashBucketEntry<T>] {
+        self.buckets
+    }
+    /// Chain values of symbols, starting from the symbol "symoffset".
+    pub fn chains(&self) -> &'a [HashChainEntry<T>] {
+        self.chains
+    }
+    /// Looks up a symbol by name.
+    ///
+    /// "symtab" and "strtab" are the symbol table this hash table is for and its string table.
+    pub fn lookup(
+        &self,
+        name: &[u8],
+        symtab: Symtab<'a, T>,
+        strtab: Strtab<'a>,
+    ) -> Option<(usize, &'a SymtabEntry<T>)> {
+        self.lookup_by(name, symtab, strtab, |_, _| true)
+    }
+    /// Looks up a symbol by name and version.
+    ///
+    /// If "version" is "None", looks up the default version.
+    pub fn lookup_versioned(
+        &self,
+        name: &[u8],
+        version: Option<&[u8]>,
+        symtab: Symtab<'a, T>,
+        strtab: Strtab<'a>,
+        versions: Versions<'a, T>,
+    ) -> Option<(usize, &'a SymtabEntry<T>)> {
+        self.lookup_by(name, symtab, strtab, |index, _| {
+            versions.matches(index, version)
+        })
+    }
+    /// Looks up a symbol by name, which satisfies the predicate.
+    pub fn lookup_by<F>(
+        &self,
+        name: &[u8],
+        symtab: Symtab<'a, T>,
+        strtab: Strtab<'a>,
+        mut predicate: F,
+    ) -> Option<(usize, &'a SymtabEntry<T>)>
+    where
+        F: FnMut(usize, &'a SymtabEntry<T>) -> bool,
+    {
+        let h = gnu_hash(name);
+        if !self.bloom_contains(h) {
+            return None;
+        }
+        let mut index = self.bucket(h)?;
+        loop {
+            let chain = self.chains.get(index - self.header.symoffset() as usize)?;
+            if chain.value() | 1 == h | 1 {
+                let entry = symtab.entries().get(index)?;
+                if strtab.find(entry.name() as usize) == Some(name) && predicate(index, entry) {
+                    return Some((index, entry));
+                }
+            }
+            if chain.value() & 1 != 0 {
+                return None;
+            }
+            index += 1;
+        }
+    }
+    /// Counts symbols in the symbol table this hash table is for, including the null symbol.
+    ///
+    /// The symbol table is not given its size in the dynamic section, so it's computed by walking the last chain.
+    pub fn symbols_num(&self) -> Option<usize> {
+        let symoffset = self.header.symoffset() as usize;
+        let last = match self.buckets.iter().map(|x| x.value() as usize).max() {
+            Some(x) if x >= symoffset => x,
+            _ => return Some(symoffset),
+        };
+        let mut index = last;
+        loop {
+            let chain = self.chains.get(index - symoffset)?;
+            if chain.value() & 1 != 0 {
+                return Some(index + 1);
+            }
+            index += 1;
+        }
+    }
+    fn bloom_contains(&self, h: u32) -> bool {
+        if self.bloom.is_empty() {
+            return false;
+        }
+        let bits = match T::CLASS {
+            Class::Class32 => 32,
+            Class::Class64 => 64,
+        };
+        let word: u64 = self.bloom[(h / bits) as usize % self.bloom.len()]
+            .value()
+            .into();
+        let shift = self.header.bloom_shift();
+        let mask = (1u64 << (h % bits)) | (1u64 << ((h >> shift) % bits));
+        word & mask == mask
+    }
+    fn bucket(&self, h: u32) -> Option<usize> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let index = self.buckets[h as usize % self.buckets.len()].value() as usize;
+        // the symbols before "symoffset" are not hashed, and zero indicates an empty bucket
+        if index == 0 || index < self.header.symoffset() as usize {
+            return None;
+        }
+        Some(index)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct GnuHashHeader<T: Context> {
+    pub nbuckets: PropU32,
+    pub symoffset: PropU32,
+    pub bloom_size: PropU32,
+    pub bloom_shift: PropU32,
+    pub _maker: PhantomData<T>,
+}
+
+impl<T: Context> GnuHashHeader<T> {
+    pub fn nbuckets(&self) -> u32 {
+        T::interpret(self.nbuckets)
+    }
+    pub fn symoffset(&self) -> u32 {
+        T::interpret(self.symoffset)
+    }
+    pub fn bloom_size(&self) -> u32 {
+        T::interpret(self.bloom_size)
+    }
+    pub fn bloom_shift(&self) -> u32 {
+        T::interpret(self.bloom_shift)
+    }
+}
+
+unsafe impl<T: Context> Pod for GnuHashHeader<T> {}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct GnuHashBloomEntry<T: Context> {
+    pub value: T::PropUsize,
+}
+
+impl<T: Context> GnuHashBloomEntry<T> {
+    pub fn value(&self) -> T::Integer {
+        T::interpret(self.value)
+    }
+}
+
+unsafe impl<T: Context> Pod for GnuHashBloomEntry<T> {}
+
+/// GNU hash function.
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    let mut r = 5381u32;
+    for x in name.iter().copied() {
+        r = r.wrapping_mul(33).wrapping_add(x as u32);
+    }
+    r
+}
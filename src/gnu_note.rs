@@ -0,0 +1,222 @@
+use crate::context::PropU32;
+use crate::context::*;
+use crate::elf::Variant;
+use crate::note::{Note, Notes};
+use crate::program::{ProgramType, Programs};
+use crate::section::{SectionType, Sections};
+use crate::utils::{read, read_s, terminate};
+
+/// ABI tag.
+pub const NOTE_GNU_ABI_TAG: u32 = 1;
+/// Hardware capabilities.
+pub const NOTE_GNU_HWCAP: u32 = 2;
+/// Build ID.
+pub const NOTE_GNU_BUILD_ID: u32 = 3;
+/// Version of the gold linker.
+pub const NOTE_GNU_GOLD_VERSION: u32 = 4;
+/// Program properties.
+pub const NOTE_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+#[derive(Debug, Clone)]
+pub enum ParseGnuNoteError {
+    BrokenAbiTag,
+    BrokenHwcap,
+}
+
+/// Note in the "GNU" namespace.
+#[derive(Debug, Clone, Copy)]
+pub enum GnuNote<'a> {
+    AbiTag(AbiTag),
+    Hwcap(Hwcap<'a>),
+    BuildId(BuildId<'a>),
+    GoldVersion(&'a [u8]),
+    /// Use "GnuProperties::parse" to parse the descriptor.
+    PropertyType0(&'a [u8]),
+    Unknown(u32, &'a [u8]),
+}
+
+impl<'a> GnuNote<'a> {
+    /// Returns "None" if the note is not in the "GNU" namespace.
+    pub fn parse<T: Context>(note: Note<'a>) -> Result<Option<Self>, ParseGnuNoteError> {
+        use GnuNote::*;
+        use ParseGnuNoteError::*;
+        if note.name() != b"GNU" {
+            return Ok(None);
+        }
+        let descriptor = note.descriptor();
+        let note = match note.typa() {
+            NOTE_GNU_ABI_TAG => AbiTag(self::AbiTag::parse::<T>(descriptor).ok_or(BrokenAbiTag)?),
+            NOTE_GNU_HWCAP => Hwcap(self::Hwcap::parse::<T>(descriptor).ok_or(BrokenHwcap)?),
+            NOTE_GNU_BUILD_ID => BuildId(self::BuildId(descriptor)),
+            // the version is not always null-terminated
+            NOTE_GNU_GOLD_VERSION => GoldVersion(terminate(descriptor).unwrap_or(descriptor)),
+            NOTE_GNU_PROPERTY_TYPE_0 => PropertyType0(descriptor),
+            x => Unknown(x, descriptor),
+        };
+        Ok(Some(note))
+    }
+}
+
+/// ABI tag, which is the earliest compatible kernel version of an operating system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbiTag {
+    pub os: AbiTagOs,
+    pub major: u32,
+    pub minor: u32,
+    pub subminor: u32,
+}
+
+impl AbiTag {
+    fn parse<T: Context>(descriptor: &[u8]) -> Option<Self> {
+        let words: &[PropU32] = read_s(descriptor)?;
+        match words {
+            [os, major, minor, subminor, ..] => Some(Self {
+                os: AbiTagOs::from(T::interpret(*os)),
+                major: T::interpret(*major),
+                minor: T::interpret(*minor),
+                subminor: T::interpret(*subminor),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbiTagOs {
+    Linux,
+    Hurd,
+    Solaris,
+    FreeBsd,
+    NetBsd,
+    Syllable,
+    Unknown(u32),
+}
+
+impl From<u32> for AbiTagOs {
+    fn from(value: u32) -> Self {
+        use AbiTagOs::*;
+        match value {
+            0 => Linux,
+            1 => Hurd,
+            2 => Solaris,
+            3 => FreeBsd,
+            4 => NetBsd,
+            5 => Syllable,
+            x => Unknown(x),
+        }
+    }
+}
+
+impl From<AbiTagOs> for u32 {
+    fn from(value: AbiTagOs) -> Self {
+        use AbiTagOs::*;
+        match value {
+            Linux => 0,
+            Hurd => 1,
+            Solaris => 2,
+            FreeBsd => 3,
+            NetBsd => 4,
+            Syllable => 5,
+            Unknown(x) => x,
+        }
+    }
+}
+
+/// Hardware capabilities, which are bit numbers with names.
+#[derive(Debug, Clone, Copy)]
+pub struct Hwcap<'a> {
+    num: u32,
+    mask: u32,
+    entries: &'a [u8],
+}
+
+impl<'a> Hwcap<'a> {
+    fn parse<T: Context>(descriptor: &'a [u8]) -> Option<Self> {
+        let num = T::interpret(*read::<PropU32>(descriptor, 0)?);
+        let mask = T::interpret(*read::<PropU32>(descriptor, 4)?);
+        let entries = &descriptor[8..];
+        let hwcap = Self { num, mask, entries };
+        if hwcap.entries().any(|x| x.is_none()) {
+            return None;
+        }
+        Some(hwcap)
+    }
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+    /// Capabilities enabled.
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+    /// Iterates over bit numbers and names.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &'a [u8])> {
+        self.entries().map(Option::unwrap)
+    }
+    fn entries(&self) -> impl Iterator<Item = Option<(u8, &'a [u8])>> {
+        let mut rest = self.entries;
+        (0..self.num).map(move |_| {
+            let (bit, tail) = rest.split_first()?;
+            let name = terminate(tail)?;
+            rest = &tail[name.len() + 1..];
+            Some((*bit, name))
+        })
+    }
+}
+
+/// Build ID, which is usually 20 bytes of SHA-1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildId<'a>(pub &'a [u8]);
+
+impl<'a> BuildId<'a> {
+    /// Finds the build ID in note programs, or in note sections if it's not found.
+    pub fn find<T: Context>(elf: Variant<'a, T>) -> Option<Self> {
+        fn search<'a, T: Context>(content: &'a [u8], alignment: T::Integer) -> Option<BuildId<'a>> {
+            let notes = Notes::<T>::parse(content, alignment).ok()?;
+            notes.iter().find_map(|x| match GnuNote::parse::<T>(x) {
+                Ok(Some(GnuNote::BuildId(x))) => Some(x),
+                _ => None,
+            })
+        }
+        if let Ok(Some(programs)) = Programs::parse(elf) {
+            for (_, program) in programs.iter() {
+                let program = match program {
+                    Ok(x) if x.header().typa() == ProgramType::Note => x,
+                    _ => continue,
+                };
+                if let Some(x) = search::<T>(program.content(), program.header().align()) {
+                    return Some(x);
+                }
+            }
+        }
+        if let Ok(Some(sections)) = Sections::parse(elf) {
+            for (_, section) in sections.iter() {
+                let section = match section {
+                    Ok(x) if x.header().typa() == SectionType::Note => x,
+                    _ => continue,
+                };
+                if let Some(x) = search::<T>(section.content(), section.header().addralign()) {
+                    return Some(x);
+                }
+            }
+        }
+        None
+    }
+    pub fn bytes(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl core::fmt::LowerHex for BuildId<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for x in self.0 {
+            write!(f, "{:02x}", x)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for BuildId<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerHex::fmt(self, f)
+    }
+}
@@ -0,0 +1,261 @@
+use crate::context::*;
+use crate::context::{PropU32, PropU64};
+use crate::elf::*;
+use crate::gnu_note::GnuNote;
+use crate::note::Notes;
+use crate::program::{Programs, PROGRAM_TYPE_GNU_PROPERTY};
+use crate::section::{SectionType, Sections};
+use crate::utils::{align, read, read_n, Pod};
+use crate::Class;
+use core::marker::PhantomData;
+
+/// Stack size.
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+/// No copy relocation on protected data symbol.
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+/// Features needed, whose values are ORed together.
+pub const GNU_PROPERTY_1_NEEDED: u32 = 0xb0008000;
+/// AArch64 features, whose values are ANDed together.
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+/// x86 features, whose values are ANDed together.
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+/// x86 features needed, whose values are ORed together.
+pub const GNU_PROPERTY_X86_FEATURE_2_NEEDED: u32 = 0xc0008001;
+/// x86 ISA levels needed, whose values are ORed together.
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+/// x86 features used, whose values are ORed together.
+pub const GNU_PROPERTY_X86_FEATURE_2_USED: u32 = 0xc0010001;
+/// x86 ISA levels used, whose values are ORed together.
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc0010002;
+
+#[derive(Debug, Clone)]
+pub enum ParseGnuPropertiesError {
+    BrokenHeader,
+    BrokenData,
+}
+
+#[derive(Debug, Clone)]
+pub enum ParseGnuPropertyError {
+    BadPropertyDatasz,
+}
+
+/// Program properties, which is the descriptor of a "NT_GNU_PROPERTY_TYPE_0" note.
+///
+/// Properties are 8-byte aligned in ELF64 and 4-byte aligned in ELF32.
+#[derive(Debug, Clone, Copy)]
+pub struct GnuProperties<'a, T: Context> {
+    content: &'a [u8],
+    _maker: PhantomData<T>,
+}
+
+impl<'a, T: Context> GnuProperties<'a, T> {
+    pub fn parse(descriptor: &'a [u8]) -> Result<Self, ParseGnuPropertiesError> {
+        let mut offset = 0usize;
+        while offset < descriptor.len() {
+            let (_, next) = Self::parse_at(descriptor, offset)?;
+            offset = next;
+        }
+        Ok(Self {
+            content: descriptor,
+            _maker: PhantomData,
+        })
+    }
+    /// Finds program properties in the "PT_GNU_PROPERTY" program, or in note sections if there is no such program.
+    pub fn find(elf: Variant<'a, T>) -> Option<Self> {
+        fn search<'a, T: Context>(
+            content: &'a [u8],
+            alignment: T::Integer,
+        ) -> Option<GnuProperties<'a, T>> {
+            let notes = Notes::<T>::parse(content, alignment).ok()?;
+            notes.iter().find_map(|x| match GnuNote::parse::<T>(x) {
+                Ok(Some(GnuNote::PropertyType0(x))) => GnuProperties::parse(x).ok(),
+                _ => None,
+            })
+        }
+        if let Ok(Some(programs)) = Programs::parse(elf) {
+            for (_, program) in programs.iter() {
+                let program = match program {
+                    Ok(x) if x.header().typa() == PROGRAM_TYPE_GNU_PROPERTY => x,
+                    _ => continue,
+                };
+                return search::<T>(program.content(), program.header().align());
+            }
+        }
+        if let Ok(Some(sections)) = Sections::parse(elf) {
+            for (_, section) in sections.iter() {
+                let section = match section {
+                    Ok(x) if x.header().typa() == SectionType::Note => x,
+                    _ => continue,
+                };
+                if let Some(x) = search::<T>(section.content(), section.header().addralign()) {
+                    return Some(x);
+                }
+            }
+        }
+        None
+    }
+    pub fn iter(&self) -> impl Iterator<Item = RawGnuProperty<'a, T>> {
+        let content = self.content;
+        let mut offset = 0usize;
+        core::iter::from_fn(move || {
+            if offset >= content.len() {
+                return None;
+            }
+            let (property, next) = Self::parse_at(content, offset).unwrap();
+            offset = next;
+            Some(property)
+        })
+    }
+    /// Returns the property and the offset of the next property.
+    fn parse_at(
+        content: &'a [u8],
+        mut offset: usize,
+    ) -> Result<(RawGnuProperty<'a, T>, usize), ParseGnuPropertiesError> {
+        use ParseGnuPropertiesError::*;
+        let alignment = match T::CLASS {
+            Class::Class32 => 4,
+            Class::Class64 => 8,
+        };
+        let header: &'a GnuPropertyHeader<T> = read(content, offset).ok_or(BrokenHeader)?;
+        offset += core::mem::size_of::<GnuPropertyHeader<T>>();
+        let data = read_n::<u8>(content, offset, header.datasz() as usize).ok_or(BrokenData)?;
+        offset += header.datasz() as usize;
+        offset = align(offset, alignment);
+        Ok((RawGnuProperty { header, data }, offset))
+    }
+}
+
+/// Property, which is not decoded.
+#[derive(Debug, Clone, Copy)]
+pub struct RawGnuProperty<'a, T: Context> {
+    header: &'a GnuPropertyHeader<T>,
+    data: &'a [u8],
+}
+
+impl<'a, T: Context> RawGnuProperty<'a, T> {
+    pub fn header(&self) -> &'a GnuPropertyHeader<T> {
+        self.header
+    }
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+    /// Decodes the property with "e_machine" of the ELF header, since processor-specific property types overlap.
+    pub fn parse(&self, machine: u16) -> Result<GnuProperty<'a>, ParseGnuPropertyError> {
+        use GnuProperty::*;
+        use ParseGnuPropertyError::*;
+        let u32_data = || -> Result<u32, ParseGnuPropertyError> {
+            match self.data.len() {
+                4 => Ok(T::interpret(*read::<PropU32>(self.data, 0).unwrap())),
+                _ => Err(BadPropertyDatasz),
+            }
+        };
+        let x86 = matches!(
+            machine,
+            ELF_MACHINE_X86 | ELF_MACHINE_X86_64 | ELF_MACHINE_IAMCU
+        );
+        let property = match self.header.typa() {
+            GNU_PROPERTY_STACK_SIZE => {
+                let size = match (T::CLASS, self.data.len()) {
+                    (Class::Class32, 4) => {
+                        T::interpret(*read::<PropU32>(self.data, 0).unwrap()).into()
+                    }
+                    (Class::Class64, 8) => T::interpret(*read::<PropU64>(self.data, 0).unwrap()),
+                    _ => return Err(BadPropertyDatasz),
+                };
+                StackSize(size)
+            }
+            GNU_PROPERTY_NO_COPY_ON_PROTECTED => NoCopyOnProtected,
+            GNU_PROPERTY_1_NEEDED => Needed1(u32_data()?),
+            GNU_PROPERTY_AARCH64_FEATURE_1_AND if machine == ELF_MACHINE_AARCH64 => {
+                Aarch64Feature1And(Aarch64Feature1(u32_data()?))
+            }
+            GNU_PROPERTY_X86_FEATURE_1_AND if x86 => X86Feature1And(X86Feature1(u32_data()?)),
+            GNU_PROPERTY_X86_FEATURE_2_NEEDED if x86 => X86Feature2Needed(u32_data()?),
+            GNU_PROPERTY_X86_FEATURE_2_USED if x86 => X86Feature2Used(u32_data()?),
+            GNU_PROPERTY_X86_ISA_1_NEEDED if x86 => X86Isa1Needed(X86Isa1(u32_data()?)),
+            GNU_PROPERTY_X86_ISA_1_USED if x86 => X86Isa1Used(X86Isa1(u32_data()?)),
+            x => Unknown(x, self.data),
+        };
+        Ok(property)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct GnuPropertyHeader<T: Context> {
+    pub typa: PropU32,
+    pub datasz: PropU32,
+    pub _maker: PhantomData<T>,
+}
+
+impl<T: Context> GnuPropertyHeader<T> {
+    pub fn typa(&self) -> u32 {
+        T::interpret(self.typa)
+    }
+    pub fn datasz(&self) -> u32 {
+        T::interpret(self.datasz)
+    }
+}
+
+unsafe impl<T: Context> Pod for GnuPropertyHeader<T> {}
+
+/// Decoded property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GnuProperty<'a> {
+    /// Stack size.
+    StackSize(u64),
+    /// No copy relocation on protected data symbol.
+    NoCopyOnProtected,
+    /// Features needed.
+    Needed1(u32),
+    /// AArch64 features supported by all objects.
+    Aarch64Feature1And(Aarch64Feature1),
+    /// x86 features supported by all objects.
+    X86Feature1And(X86Feature1),
+    /// x86 features needed.
+    X86Feature2Needed(u32),
+    /// x86 features used.
+    X86Feature2Used(u32),
+    /// x86 ISA levels needed.
+    X86Isa1Needed(X86Isa1),
+    /// x86 ISA levels used.
+    X86Isa1Used(X86Isa1),
+    /// Unknown property.
+    Unknown(u32, &'a [u8]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, From, Into, BitXor, BitAnd, BitOr, LowerHex)]
+pub struct X86Feature1(pub u32);
+
+impl X86Feature1 {
+    /// Indirect branch tracking.
+    pub const IBT: Self = Self(0x1);
+    /// Shadow stack.
+    pub const SHSTK: Self = Self(0x2);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, From, Into, BitXor, BitAnd, BitOr, LowerHex)]
+pub struct X86Isa1(pub u32);
+
+impl X86Isa1 {
+    /// x86-64 baseline.
+    pub const BASELINE: Self = Self(0x1);
+    /// x86-64-v2.
+    pub const V2: Self = Self(0x2);
+    /// x86-64-v3.
+    pub const V3: Self = Self(0x4);
+    /// x86-64-v4.
+    pub const V4: Self = Self(0x8);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, From, Into, BitXor, BitAnd, BitOr, LowerHex)]
+pub struct Aarch64Feature1(pub u32);
+
+impl Aarch64Feature1 {
+    /// Branch target identification.
+    pub const BTI: Self = Self(0x1);
+    /// Pointer authentication.
+    pub const PAC: Self = Self(0x2);
+    /// Guarded control stack.
+    pub const GCS: Self = Self(0x4);
+}
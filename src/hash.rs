@@ -1,6 +1,9 @@
 use crate::context::PropU32;
 use crate::context::*;
+use crate::strtab::Strtab;
+use crate::symtab::{Symtab, SymtabEntry};
 use crate::utils::{read, read_n, Pod};
+use crate::version::Versions;
 use core::marker::PhantomData;
 
 #[derive(Debug, Clone)]
@@ -22,6 +25,7 @@ impl<'a, T: Context> Hash<'a, T> {
         use ParseHashError::*;
         let mut offset = 0usize;
         let header: &HashHeader<T> = read(content, offset).ok_or(BrokenHeader)?;
+        offset += core::mem::size_of::<HashHeader<T>>();
         let buckets: &[HashBucketEntry<T>] =
             read_n(content, offset, header.nbuckets() as usize).ok_or(BrokenBuckets)?;
         offset += core::mem::size_of::<HashBucketEntry<T>>() * header.nbuckets() as usize;
@@ -36,6 +40,61 @@ impl<'a, T: Context> Hash<'a, T> {
     pub fn chains(&self) -> &'a [HashChainEntry<T>] {
         self.chains
     }
+    /// Looks up a symbol by name.
+    ///
+    /// "symtab" and "strtab" are the symbol table this hash table is for and its string table.
+    pub fn lookup(
+        &self,
+        name: &[u8],
+        symtab: Symtab<'a, T>,
+        strtab: Strtab<'a>,
+    ) -> Option<(usize, &'a SymtabEntry<T>)> {
+        self.lookup_by(name, symtab, strtab, |_, _| true)
+    }
+    /// Looks up a symbol by name and version.
+    ///
+    /// If "version" is "None", looks up the default version.
+    pub fn lookup_versioned(
+        &self,
+        name: &[u8],
+        version: Option<&[u8]>,
+        symtab: Symtab<'a, T>,
+        strtab: Strtab<'a>,
+        versions: Versions<'a, T>,
+    ) -> Option<(usize, &'a SymtabEntry<T>)> {
+        self.lookup_by(name, symtab, strtab, |index, _| {
+            versions.matches(index, version)
+        })
+    }
+    /// Looks up a symbol by name, which satisfies the predicate.
+    pub fn lookup_by<F>(
+        &self,
+        name: &[u8],
+        symtab: Symtab<'a, T>,
+        strtab: Strtab<'a>,
+        mut predicate: F,
+    ) -> Option<(usize, &'a SymtabEntry<T>)>
+    where
+        F: FnMut(usize, &'a SymtabEntry<T>) -> bool,
+    {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let bucket = hash(name) as usize % self.buckets.len();
+        let mut index = self.buckets[bucket].value() as usize;
+        // a chain never visits a symbol twice, so it's broken if it's longer than the table
+        for _ in 0..self.chains.len() {
+            if index == 0 {
+                return None;
+            }
+            let entry = symtab.entries().get(index)?;
+            if strtab.find(entry.name() as usize) == Some(name) && predicate(index, entry) {
+                return Some((index, entry));
+            }
+            index = self.chains.get(index)?.value() as usize;
+        }
+        None
+    }
 }
 
 #[repr(C)]
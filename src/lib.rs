@@ -16,33 +16,83 @@
 //! | Strtab, Rela, Hash, Dynamic, Note, Rel, Group, Interp | {type}::parse    |
 //! | InitArray, FiniArray, PreinitArray                    | Array::parse     |
 //! | SymtabShndx                                           | Shndx::parse     |
+//! | GnuHash                                               | GnuHash::parse   |
+//! | GnuVersym, GnuVerdef, GnuVerneed                      | {type}::parse    |
 //!
 //! You need to call "Compression::parse" for compressed sections.
 //!
+//! You can call "Symbols::parse" to resolve names and section indexes of symbols in a symtab section.
+//! You can call "Symbolizer::parse" to look up symbols by addresses with the "alloc" feature.
+//!
+//! You can call "DynamicInfo::parse" to resolve needed libraries, the soname and search paths in the dynamic program.
+//! You can call "DynamicView::parse" to find dynamic symbols, relocations, hash tables and versions without section headers.
+//!
+//! You can call "GnuNote::parse" and "GnuProperties::parse" to decode notes in the "GNU" namespace, and "CoreNote::parse" to decode notes in core dumps.
+//!
+//! You can call "CoreImage::parse" to read memory and loaded modules of a core dump.
+//!
+//! You can call "Section::decompressed" to decompress compressed sections with the "alloc" feature, and "Sections::decompressed_by_name" to find debug sections compressed in either the ELF or the GNU style.
+//!
+//! You can call "MiniDebugInfo::find" to decompress the ELF file embedded in ".gnu_debugdata" with the "minidebuginfo" feature.
+//!
+//! You can call "DebugLink::find" and "DebugAltLink::find" to find separate debug files, and "DebugFileResolver::resolve" to open them with the "std" feature.
+//!
+//! You can call "DebuginfodClient::fetch" to download debug files and sources by build ID, and "DebuginfodIndex::serve" to serve local ELF files by build ID with the "debuginfod" feature.
+//!
+//! You can call "DebugLine::find" to map addresses to source locations with the "dwarf" feature.
+//!
 //! You can read "examples/readelf" for a starter with this crate.
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
 #[macro_use]
 extern crate derive_more;
 
 pub mod array;
 pub mod compression;
 pub mod context;
+pub mod core_image;
+pub mod core_note;
+#[cfg(feature = "std")]
+pub mod debug_file;
+pub mod debug_link;
+#[cfg(feature = "debuginfod")]
+pub mod debuginfod;
+#[cfg(feature = "debuginfod")]
+pub mod debuginfod_server;
+#[cfg(feature = "dwarf")]
+pub mod dwarf;
 pub mod dynamic;
+pub mod dynamic_info;
 pub mod elf;
+pub mod gnu_hash;
+pub mod gnu_note;
+pub mod gnu_property;
 pub mod group;
 pub mod hash;
 pub mod ident;
 pub mod interp;
+#[cfg(feature = "minidebuginfo")]
+pub mod mini_debug_info;
 pub mod note;
 pub mod program;
 pub mod rel;
 pub mod rela;
+pub mod relocation;
 pub mod section;
 pub mod shndx;
 pub mod strtab;
+#[cfg(feature = "alloc")]
+pub mod symbolizer;
+pub mod symbols;
 pub mod symtab;
+pub mod version;
 
 mod utils;
 
@@ -0,0 +1,78 @@
+use crate::context::*;
+use crate::elf::{Elf, ParseElfError, Variant};
+use crate::section::Sections;
+use std::vec::Vec;
+
+#[derive(Debug, Clone)]
+pub enum ParseMiniDebugInfoError {
+    BrokenXz,
+    TooLarge,
+    FromElf(ParseElfError),
+}
+
+/// MiniDebugInfo, which is an ELF file compressed by xz in the ".gnu_debugdata" section.
+///
+/// The nested ELF file usually only contains a ".symtab" section of the symbols missing from ".dynsym".
+#[derive(Debug, Clone)]
+pub struct MiniDebugInfo {
+    data: Vec<u8>,
+}
+
+impl MiniDebugInfo {
+    /// Finds and decompresses the ".gnu_debugdata" section, which is limited to "max_size" bytes.
+    pub fn find<T: Context>(
+        elf: Variant<'_, T>,
+        max_size: usize,
+    ) -> Option<Result<Self, ParseMiniDebugInfoError>> {
+        let sections = Sections::parse(elf).ok()??;
+        let section = sections.by_name(b".gnu_debugdata")?;
+        Some(Self::parse(section.content(), max_size))
+    }
+    /// Decompresses the content of a ".gnu_debugdata" section, which is limited to "max_size" bytes.
+    pub fn parse(content: &[u8], max_size: usize) -> Result<Self, ParseMiniDebugInfoError> {
+        use ParseMiniDebugInfoError::*;
+        let mut output = Limited {
+            data: Vec::new(),
+            max_size,
+            exceeded: false,
+        };
+        if lzma_rs::xz_decompress(&mut &content[..], &mut output).is_err() {
+            return Err(if output.exceeded { TooLarge } else { BrokenXz });
+        }
+        let data = output.data;
+        Elf::parse(&data).map_err(FromElf)?;
+        Ok(Self { data })
+    }
+    /// The decompressed ELF file.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn elf(&self) -> Elf<'_> {
+        Elf::parse(&self.data).unwrap()
+    }
+    /// Returns the nested ELF file if it matches the context, which is the context of the outer ELF file usually.
+    pub fn variant<T: Context>(&self) -> Option<Variant<'_, T>> {
+        Variant::checked_parse(&self.data)
+    }
+}
+
+/// Output of the decoder, which fails once it grows beyond "max_size".
+struct Limited {
+    data: Vec<u8>,
+    max_size: usize,
+    exceeded: bool,
+}
+
+impl std::io::Write for Limited {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.len() > self.max_size - self.data.len() {
+            self.exceeded = true;
+            return Err(std::io::ErrorKind::OutOfMemory.into());
+        }
+        self.data.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
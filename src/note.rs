@@ -11,6 +11,12 @@ pub enum ParseNoteError {
     BrokenDescriptor,
 }
 
+#[derive(Debug, Clone)]
+pub enum ParseNotesError {
+    BadPropertyAlign,
+    FromNote(ParseNoteError),
+}
+
 /// Note section/program.
 #[derive(Debug, Clone, Copy)]
 pub struct Note<'a> {
@@ -20,24 +26,36 @@ pub struct Note<'a> {
 }
 
 impl<'a> Note<'a> {
+    /// Parses the first note, assuming 4-byte alignment.
+    ///
+    /// Use "Notes::parse" to parse all notes.
     pub fn parse<T: Context>(content: &'a [u8]) -> Result<Self, ParseNoteError> {
+        Self::parse_at::<T>(content, 0, 4).map(|(note, _)| note)
+    }
+    /// Returns the note and the offset of the next note.
+    fn parse_at<T: Context>(
+        content: &'a [u8],
+        mut offset: usize,
+        alignment: usize,
+    ) -> Result<(Self, usize), ParseNoteError> {
         use ParseNoteError::*;
-        let mut offset = 0usize;
         let header: &NoteHeader<T> = read(content, offset).ok_or(BrokenHeader)?;
         offset += core::mem::size_of::<NoteHeader<T>>();
         let name: &[u8] =
             terminate(read_n(content, offset, header.name_size() as usize).ok_or(BrokenName)?)
                 .ok_or(BadStringName)?;
         offset += header.name_size() as usize;
-        offset = align(offset, core::mem::align_of::<T::Integer>());
+        offset = align(offset, alignment);
         let descriptor = read_n::<u8>(content, offset, header.descriptor_size() as usize)
             .ok_or(BrokenDescriptor)?;
-        // offset += header.descriptor_size() as usize;
-        Ok(Self {
+        offset += header.descriptor_size() as usize;
+        offset = align(offset, alignment);
+        let note = Self {
             typa: header.typa(),
             name,
             descriptor,
-        })
+        };
+        Ok((note, offset))
     }
     pub fn typa(&self) -> u32 {
         self.typa
@@ -50,6 +68,54 @@ impl<'a> Note<'a> {
     }
 }
 
+/// All notes in a note section/program.
+#[derive(Debug, Clone, Copy)]
+pub struct Notes<'a, T: Context> {
+    content: &'a [u8],
+    alignment: usize,
+    _maker: PhantomData<T>,
+}
+
+impl<'a, T: Context> Notes<'a, T> {
+    /// "alignment" is "sh_addralign" of the section or "p_align" of the program.
+    ///
+    /// Names and descriptors are padded to 8 bytes if it's 8, or 4 bytes if it's not greater than 4.
+    pub fn parse(content: &'a [u8], alignment: T::Integer) -> Result<Self, ParseNotesError> {
+        use ParseNotesError::*;
+        let alignment = match alignment.into() {
+            0..=4 => 4,
+            8 => 8,
+            _ => return Err(BadPropertyAlign),
+        };
+        let mut offset = 0usize;
+        while offset < content.len() {
+            let (_, next) = Note::parse_at::<T>(content, offset, alignment).map_err(FromNote)?;
+            offset = next;
+        }
+        Ok(Self {
+            content,
+            alignment,
+            _maker: PhantomData,
+        })
+    }
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Note<'a>> {
+        let content = self.content;
+        let alignment = self.alignment;
+        let mut offset = 0usize;
+        core::iter::from_fn(move || {
+            if offset >= content.len() {
+                return None;
+            }
+            let (note, next) = Note::parse_at::<T>(content, offset, alignment).unwrap();
+            offset = next;
+            Some(note)
+        })
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct NoteHeader<T: Context> {
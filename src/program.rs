@@ -47,6 +47,75 @@ impl<'a, T: Context> Programs<'a, T> {
     pub fn num(&self) -> u16 {
         self.num
     }
+    pub fn iter(&self) -> impl Iterator<Item = (u16, Result<Program<'a, T>, ParseProgramError>)> {
+        let programs = *self;
+        (0..self.num).map(move |i| (i, Program::parse(programs, i).unwrap()))
+    }
+    /// Translates a virtual address to a file offset through load programs.
+    ///
+    /// Returns "None" if the address is not mapped, or it's beyond "p_filesz" of its load program.
+    pub fn vaddr_to_offset(&self, vaddr: u64) -> Option<u64> {
+        let (program, delta) = self.load_at_vaddr(vaddr)?;
+        if delta >= program.header().filesz().into() {
+            return None;
+        }
+        Into::<u64>::into(program.header().offset()).checked_add(delta)
+    }
+    /// Translates a file offset to a virtual address through load programs.
+    pub fn offset_to_vaddr(&self, offset: u64) -> Option<u64> {
+        self.loads().find_map(|x| {
+            let delta = offset.checked_sub(x.header().offset().into())?;
+            if delta >= x.header().filesz().into() {
+                return None;
+            }
+            Into::<u64>::into(x.header().vaddr()).checked_add(delta)
+        })
+    }
+    /// Reads the content backing the range of virtual addresses through load programs.
+    ///
+    /// Returns "None" if the range is not mapped by a load program, or it's across "p_filesz" of its load program.
+    pub fn read_at_vaddr(&self, vaddr: u64, len: usize) -> Option<VaddrContent<'a>> {
+        let (program, delta) = self.load_at_vaddr(vaddr)?;
+        let end = delta.checked_add(len as u64)?;
+        if end > program.header().memsz().into() {
+            return None;
+        }
+        let filesz: u64 = program.header().filesz().into();
+        if end <= filesz {
+            Some(VaddrContent::File(
+                &program.content()[delta as usize..end as usize],
+            ))
+        } else if delta >= filesz {
+            Some(VaddrContent::Bss)
+        } else {
+            None
+        }
+    }
+    /// Returns the file content from the virtual address to "p_filesz" of its load program, for tables whose size is known only after parsing them.
+    ///
+    /// Returns "None" if the address is not mapped, or it's beyond "p_filesz" of its load program.
+    pub fn content_at_vaddr(&self, vaddr: u64) -> Option<&'a [u8]> {
+        let (program, delta) = self.load_at_vaddr(vaddr)?;
+        if delta >= program.header().filesz().into() {
+            return None;
+        }
+        program.content().get(delta as usize..)
+    }
+    /// Returns the load program containing the virtual address, and the address relative to the load program.
+    fn load_at_vaddr(&self, vaddr: u64) -> Option<(Program<'a, T>, u64)> {
+        self.loads().find_map(|x| {
+            let delta = vaddr.checked_sub(x.header().vaddr().into())?;
+            if delta >= x.header().memsz().into() {
+                return None;
+            }
+            Some((x, delta))
+        })
+    }
+    fn loads(&self) -> impl Iterator<Item = Program<'a, T>> {
+        self.iter()
+            .filter_map(|(_, x)| x.ok())
+            .filter(|x| x.header().typa() == ProgramType::Load)
+    }
 }
 
 pub struct Program<'a, T: Context> {
@@ -202,6 +271,18 @@ impl From<ProgramType> for u32 {
     }
 }
 
+/// Content backing a range of virtual addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaddrContent<'a> {
+    /// Bytes in the file.
+    File(&'a [u8]),
+    /// Bytes beyond "p_filesz", which are zero-initialized in memory.
+    Bss,
+}
+
+/// GNU program properties.
+pub const PROGRAM_TYPE_GNU_PROPERTY: ProgramType = ProgramType::OsSpecific(0x6474e553);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, From, Into, BitXor, BitAnd, BitOr, LowerHex)]
 pub struct ProgramFlags(pub u32);
 
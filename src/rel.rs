@@ -1,5 +1,7 @@
 use crate::context::*;
-use crate::utils::{read_s, Pod};
+use crate::relocation::RelocationType;
+use crate::utils::{read_s, relocation_sym, relocation_type, Pod};
+use crate::Data;
 
 #[derive(Debug, Clone)]
 pub enum ParseRelError {
@@ -37,6 +39,62 @@ impl<T: Context> RelEntry<T> {
     pub fn info(&self) -> T::Integer {
         T::interpret(self.info)
     }
+    /// Symbol table index, where "machine" is "e_machine" of the ELF header.
+    pub fn sym(&self, machine: u16) -> u32 {
+        relocation_sym::<T>(machine, self.info())
+    }
+    /// Relocation type, where "machine" is "e_machine" of the ELF header.
+    ///
+    /// For MIPS64, it's the first relocation type, and "RelEntry::mips64" gives all of them.
+    pub fn typa(&self, machine: u16) -> u32 {
+        relocation_type::<T>(machine, self.info())
+    }
+    /// Decodes the relocation type, where "machine" is "e_machine" of the ELF header.
+    pub fn checked_type(&self, machine: u16) -> Option<RelocationType> {
+        RelocationType::parse(machine, self.typa(machine))
+    }
+    /// Relocation information in the MIPS64 layout.
+    pub fn mips64(&self) -> Mips64Info {
+        Mips64Info::parse::<T>(self.info())
+    }
 }
 
 unsafe impl<T: Context> Pod for RelEntry<T> {}
+
+/// Relocation information in the MIPS64 layout.
+///
+/// A MIPS64 relocation entry holds a 32-bit symbol index in the file's byte order, followed by a special symbol index and three relocation types, one byte each.
+/// So little endian files can not be decoded as other ELF64 files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mips64Info {
+    /// Symbol table index.
+    pub sym: u32,
+    /// Special symbol index.
+    pub ssym: u8,
+    /// The third relocation type.
+    pub typa3: u8,
+    /// The second relocation type.
+    pub typa2: u8,
+    /// The first relocation type.
+    pub typa: u8,
+}
+
+impl Mips64Info {
+    /// Decodes the field "r_info" of an ELF64 relocation entry.
+    pub fn parse<T: Context>(info: T::Integer) -> Self {
+        use Data::*;
+        let x: u64 = info.into();
+        // "x" is read in the file's byte order, so fields are placed in reversed orders in little and big endian.
+        let (sym, rest) = match T::DATA {
+            Little => (x as u32, ((x >> 32) as u32).swap_bytes()),
+            Big => ((x >> 32) as u32, x as u32),
+        };
+        Self {
+            sym,
+            ssym: (rest >> 24) as u8,
+            typa3: (rest >> 16) as u8,
+            typa2: (rest >> 8) as u8,
+            typa: rest as u8,
+        }
+    }
+}
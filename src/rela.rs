@@ -1,5 +1,7 @@
 use crate::context::*;
-use crate::utils::{read_s, Pod};
+use crate::rel::Mips64Info;
+use crate::relocation::RelocationType;
+use crate::utils::{as_signed, read_s, relocation_sym, relocation_type, Pod};
 
 #[derive(Debug, Clone)]
 pub enum ParseRelaError {
@@ -38,9 +40,31 @@ impl<T: Context> RelaEntry<T> {
     pub fn info(&self) -> T::Integer {
         T::interpret(self.info)
     }
+    /// Symbol table index, where "machine" is "e_machine" of the ELF header.
+    pub fn sym(&self, machine: u16) -> u32 {
+        relocation_sym::<T>(machine, self.info())
+    }
+    /// Relocation type, where "machine" is "e_machine" of the ELF header.
+    ///
+    /// For MIPS64, it's the first relocation type, and "RelaEntry::mips64" gives all of them.
+    pub fn typa(&self, machine: u16) -> u32 {
+        relocation_type::<T>(machine, self.info())
+    }
+    /// Decodes the relocation type, where "machine" is "e_machine" of the ELF header.
+    pub fn checked_type(&self, machine: u16) -> Option<RelocationType> {
+        RelocationType::parse(machine, self.typa(machine))
+    }
+    /// Relocation information in the MIPS64 layout.
+    pub fn mips64(&self) -> Mips64Info {
+        Mips64Info::parse::<T>(self.info())
+    }
     pub fn addend(&self) -> T::Integer {
         T::interpret(self.addend)
     }
+    /// Signed addend, sign-extended to 64 bits.
+    pub fn signed_addend(&self) -> i64 {
+        as_signed::<T>(self.addend())
+    }
 }
 
 unsafe impl<T: Context> Pod for RelaEntry<T> {}
@@ -0,0 +1,1497 @@
+//! Architecture-specific relocation types.
+//!
+//! Use "RelEntry::checked_type" or "RelaEntry::checked_type" to decode the relocation type of an entry, with "ElfHeader::machine" as the architecture.
+
+use crate::elf::*;
+
+macro_rules! relocation_types {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal, $string:literal;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl TryFrom<u32> for $name {
+            type Error = ();
+
+            fn try_from(value: u32) -> Result<Self, Self::Error> {
+                match value {
+                    $($value => Ok($name::$variant),)*
+                    _ => Err(()),
+                }
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                }
+            }
+        }
+
+        impl $name {
+            /// The name in the specification, e.g. "R_X86_64_GLOB_DAT".
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $string,)*
+                }
+            }
+        }
+    };
+}
+
+/// Relocation type, decoded by the architecture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocationType {
+    /// x86-64.
+    X86_64(RelocationX86_64),
+    /// i386.
+    I386(RelocationI386),
+    /// AArch64.
+    Aarch64(RelocationAarch64),
+    /// ARM.
+    Arm(RelocationArm),
+    /// RISC-V.
+    Riscv(RelocationRiscv),
+    /// PowerPC64.
+    Ppc64(RelocationPpc64),
+    /// MIPS.
+    Mips(RelocationMips),
+    /// s390 and s390x.
+    S390(RelocationS390),
+    /// LoongArch.
+    LoongArch(RelocationLoongArch),
+}
+
+impl RelocationType {
+    /// Decodes a relocation type with "e_machine" of the ELF header.
+    ///
+    /// Returns "None" if the architecture is not supported or the relocation type is unknown.
+    /// For MIPS64, pass each relocation type in "Mips64Info".
+    pub fn parse(machine: u16, typa: u32) -> Option<Self> {
+        use RelocationType::*;
+        match machine {
+            ELF_MACHINE_X86_64 => typa.try_into().ok().map(X86_64),
+            ELF_MACHINE_X86 => typa.try_into().ok().map(I386),
+            ELF_MACHINE_AARCH64 => typa.try_into().ok().map(Aarch64),
+            ELF_MACHINE_ARM => typa.try_into().ok().map(Arm),
+            ELF_MACHINE_RISCV => typa.try_into().ok().map(Riscv),
+            ELF_MACHINE_PPC64 => typa.try_into().ok().map(Ppc64),
+            ELF_MACHINE_MIPS | ELF_MACHINE_MIPS_RS3_LE => typa.try_into().ok().map(Mips),
+            ELF_MACHINE_S390 => typa.try_into().ok().map(S390),
+            ELF_MACHINE_LOONGARCH => typa.try_into().ok().map(LoongArch),
+            _ => None,
+        }
+    }
+    /// The name in the specification, e.g. "R_X86_64_GLOB_DAT".
+    pub fn name(&self) -> &'static str {
+        use RelocationType::*;
+        match self {
+            X86_64(x) => x.name(),
+            I386(x) => x.name(),
+            Aarch64(x) => x.name(),
+            Arm(x) => x.name(),
+            Riscv(x) => x.name(),
+            Ppc64(x) => x.name(),
+            Mips(x) => x.name(),
+            S390(x) => x.name(),
+            LoongArch(x) => x.name(),
+        }
+    }
+}
+
+impl From<RelocationType> for u32 {
+    fn from(value: RelocationType) -> Self {
+        use RelocationType::*;
+        match value {
+            X86_64(x) => x.into(),
+            I386(x) => x.into(),
+            Aarch64(x) => x.into(),
+            Arm(x) => x.into(),
+            Riscv(x) => x.into(),
+            Ppc64(x) => x.into(),
+            Mips(x) => x.into(),
+            S390(x) => x.into(),
+            LoongArch(x) => x.into(),
+        }
+    }
+}
+
+relocation_types! {
+    /// x86-64 relocation types.
+    RelocationX86_64 {
+        /// No reloc.
+        None = 0, "R_X86_64_NONE";
+        /// Direct 64 bit.
+        Data64 = 1, "R_X86_64_64";
+        /// PC relative 32 bit signed.
+        Pc32 = 2, "R_X86_64_PC32";
+        /// 32 bit GOT entry.
+        Got32 = 3, "R_X86_64_GOT32";
+        /// 32 bit PLT address.
+        Plt32 = 4, "R_X86_64_PLT32";
+        /// Copy symbol at runtime.
+        Copy = 5, "R_X86_64_COPY";
+        /// Create GOT entry.
+        GlobDat = 6, "R_X86_64_GLOB_DAT";
+        /// Create PLT entry.
+        JumpSlot = 7, "R_X86_64_JUMP_SLOT";
+        /// Adjust by program base.
+        Relative = 8, "R_X86_64_RELATIVE";
+        /// 32 bit signed PC relative offset to GOT.
+        Gotpcrel = 9, "R_X86_64_GOTPCREL";
+        /// Direct 32 bit zero extended.
+        Data32 = 10, "R_X86_64_32";
+        /// Direct 32 bit sign extended.
+        Data32s = 11, "R_X86_64_32S";
+        /// Direct 16 bit zero extended.
+        Data16 = 12, "R_X86_64_16";
+        /// 16 bit sign extended pc relative.
+        Pc16 = 13, "R_X86_64_PC16";
+        /// Direct 8 bit sign extended.
+        Data8 = 14, "R_X86_64_8";
+        /// 8 bit sign extended pc relative.
+        Pc8 = 15, "R_X86_64_PC8";
+        /// ID of module containing symbol.
+        Dtpmod64 = 16, "R_X86_64_DTPMOD64";
+        /// Offset in module's TLS block.
+        Dtpoff64 = 17, "R_X86_64_DTPOFF64";
+        /// Offset in initial TLS block.
+        Tpoff64 = 18, "R_X86_64_TPOFF64";
+        /// 32 bit signed PC relative offset to two GOT entries for GD symbol.
+        Tlsgd = 19, "R_X86_64_TLSGD";
+        /// 32 bit signed PC relative offset to two GOT entries for LD symbol.
+        Tlsld = 20, "R_X86_64_TLSLD";
+        /// Offset in TLS block.
+        Dtpoff32 = 21, "R_X86_64_DTPOFF32";
+        /// 32 bit signed PC relative offset to GOT entry for IE symbol.
+        Gottpoff = 22, "R_X86_64_GOTTPOFF";
+        /// Offset in initial TLS block.
+        Tpoff32 = 23, "R_X86_64_TPOFF32";
+        /// PC relative 64 bit.
+        Pc64 = 24, "R_X86_64_PC64";
+        /// 64 bit offset to GOT.
+        Gotoff64 = 25, "R_X86_64_GOTOFF64";
+        /// 32 bit signed pc relative offset to GOT.
+        Gotpc32 = 26, "R_X86_64_GOTPC32";
+        /// 64-bit GOT entry offset.
+        Got64 = 27, "R_X86_64_GOT64";
+        /// 64-bit PC relative offset to GOT entry.
+        Gotpcrel64 = 28, "R_X86_64_GOTPCREL64";
+        /// 64-bit PC relative offset to GOT.
+        Gotpc64 = 29, "R_X86_64_GOTPC64";
+        /// Like GOT64, says PLT entry needed.
+        Gotplt64 = 30, "R_X86_64_GOTPLT64";
+        /// 64-bit GOT relative offset to PLT entry.
+        Pltoff64 = 31, "R_X86_64_PLTOFF64";
+        /// Size of symbol plus 32-bit addend.
+        Size32 = 32, "R_X86_64_SIZE32";
+        /// Size of symbol plus 64-bit addend.
+        Size64 = 33, "R_X86_64_SIZE64";
+        /// GOT offset for TLS descriptor.
+        Gotpc32Tlsdesc = 34, "R_X86_64_GOTPC32_TLSDESC";
+        /// Marker for call through TLS descriptor.
+        TlsdescCall = 35, "R_X86_64_TLSDESC_CALL";
+        /// TLS descriptor.
+        Tlsdesc = 36, "R_X86_64_TLSDESC";
+        /// Adjust indirectly by program base.
+        Irelative = 37, "R_X86_64_IRELATIVE";
+        /// 64-bit adjust by program base.
+        Relative64 = 38, "R_X86_64_RELATIVE64";
+        /// Load from 32 bit signed pc relative offset to GOT entry without REX prefix, relaxable.
+        Gotpcrelx = 41, "R_X86_64_GOTPCRELX";
+        /// Load from 32 bit signed pc relative offset to GOT entry with REX prefix, relaxable.
+        RexGotpcrelx = 42, "R_X86_64_REX_GOTPCRELX";
+    }
+}
+
+relocation_types! {
+    /// i386 relocation types.
+    RelocationI386 {
+        /// No reloc.
+        None = 0, "R_386_NONE";
+        /// Direct 32 bit.
+        Data32 = 1, "R_386_32";
+        /// PC relative 32 bit.
+        Pc32 = 2, "R_386_PC32";
+        /// 32 bit GOT entry.
+        Got32 = 3, "R_386_GOT32";
+        /// 32 bit PLT address.
+        Plt32 = 4, "R_386_PLT32";
+        /// Copy symbol at runtime.
+        Copy = 5, "R_386_COPY";
+        /// Create GOT entry.
+        GlobDat = 6, "R_386_GLOB_DAT";
+        /// Create PLT entry.
+        JmpSlot = 7, "R_386_JMP_SLOT";
+        /// Adjust by program base.
+        Relative = 8, "R_386_RELATIVE";
+        /// 32 bit offset to GOT.
+        Gotoff = 9, "R_386_GOTOFF";
+        /// 32 bit PC relative offset to GOT.
+        Gotpc = 10, "R_386_GOTPC";
+        Data32plt = 11, "R_386_32PLT";
+        /// Offset in static TLS block.
+        TlsTpoff = 14, "R_386_TLS_TPOFF";
+        /// Address of GOT entry for static TLS block offset.
+        TlsIe = 15, "R_386_TLS_IE";
+        /// GOT entry for static TLS block offset.
+        TlsGotie = 16, "R_386_TLS_GOTIE";
+        /// Offset relative to static TLS block.
+        TlsLe = 17, "R_386_TLS_LE";
+        /// Direct 32 bit for GNU version of general dynamic thread local data.
+        TlsGd = 18, "R_386_TLS_GD";
+        /// Direct 32 bit for GNU version of local dynamic thread local data in LE code.
+        TlsLdm = 19, "R_386_TLS_LDM";
+        Data16 = 20, "R_386_16";
+        Pc16 = 21, "R_386_PC16";
+        Data8 = 22, "R_386_8";
+        Pc8 = 23, "R_386_PC8";
+        /// Direct 32 bit for general dynamic thread local data.
+        TlsGd32 = 24, "R_386_TLS_GD_32";
+        /// Tag for pushl in GD TLS code.
+        TlsGdPush = 25, "R_386_TLS_GD_PUSH";
+        /// Relocation for call to __tls_get_addr().
+        TlsGdCall = 26, "R_386_TLS_GD_CALL";
+        /// Tag for popl in GD TLS code.
+        TlsGdPop = 27, "R_386_TLS_GD_POP";
+        /// Direct 32 bit for local dynamic thread local data in LE code.
+        TlsLdm32 = 28, "R_386_TLS_LDM_32";
+        /// Tag for pushl in LDM TLS code.
+        TlsLdmPush = 29, "R_386_TLS_LDM_PUSH";
+        /// Relocation for call to __tls_get_addr() in LDM code.
+        TlsLdmCall = 30, "R_386_TLS_LDM_CALL";
+        /// Tag for popl in LDM TLS code.
+        TlsLdmPop = 31, "R_386_TLS_LDM_POP";
+        /// Offset relative to TLS block.
+        TlsLdo32 = 32, "R_386_TLS_LDO_32";
+        /// GOT entry for negated static TLS block offset.
+        TlsIe32 = 33, "R_386_TLS_IE_32";
+        /// Negated offset relative to static TLS block.
+        TlsLe32 = 34, "R_386_TLS_LE_32";
+        /// ID of module containing symbol.
+        TlsDtpmod32 = 35, "R_386_TLS_DTPMOD32";
+        /// Offset in TLS block.
+        TlsDtpoff32 = 36, "R_386_TLS_DTPOFF32";
+        /// Negated offset in static TLS block.
+        TlsTpoff32 = 37, "R_386_TLS_TPOFF32";
+        /// 32-bit symbol size.
+        Size32 = 38, "R_386_SIZE32";
+        /// GOT offset for TLS descriptor.
+        TlsGotdesc = 39, "R_386_TLS_GOTDESC";
+        /// Marker of call through TLS descriptor for relaxation.
+        TlsDescCall = 40, "R_386_TLS_DESC_CALL";
+        /// TLS descriptor containing pointer to code and to argument, returning the TLS offset for the symbol.
+        TlsDesc = 41, "R_386_TLS_DESC";
+        /// Adjust indirectly by program base.
+        Irelative = 42, "R_386_IRELATIVE";
+        /// Load from 32 bit GOT entry, relaxable.
+        Got32x = 43, "R_386_GOT32X";
+    }
+}
+
+relocation_types! {
+    /// AArch64 relocation types.
+    RelocationAarch64 {
+        /// No relocation.
+        None = 0, "R_AARCH64_NONE";
+        /// Direct 32 bit.
+        P32Abs32 = 1, "R_AARCH64_P32_ABS32";
+        /// Copy symbol at runtime.
+        P32Copy = 180, "R_AARCH64_P32_COPY";
+        /// Create GOT entry.
+        P32GlobDat = 181, "R_AARCH64_P32_GLOB_DAT";
+        /// Create PLT entry.
+        P32JumpSlot = 182, "R_AARCH64_P32_JUMP_SLOT";
+        /// Adjust by program base.
+        P32Relative = 183, "R_AARCH64_P32_RELATIVE";
+        /// Module number, 32 bit.
+        P32TlsDtpmod = 184, "R_AARCH64_P32_TLS_DTPMOD";
+        /// Module-relative offset, 32 bit.
+        P32TlsDtprel = 185, "R_AARCH64_P32_TLS_DTPREL";
+        /// TP-relative offset, 32 bit.
+        P32TlsTprel = 186, "R_AARCH64_P32_TLS_TPREL";
+        /// TLS Descriptor.
+        P32Tlsdesc = 187, "R_AARCH64_P32_TLSDESC";
+        /// STT_GNU_IFUNC relocation.
+        P32Irelative = 188, "R_AARCH64_P32_IRELATIVE";
+        /// Direct 64 bit.
+        Abs64 = 257, "R_AARCH64_ABS64";
+        /// Direct 32 bit.
+        Abs32 = 258, "R_AARCH64_ABS32";
+        /// Direct 16-bit.
+        Abs16 = 259, "R_AARCH64_ABS16";
+        /// PC-relative 64-bit.
+        Prel64 = 260, "R_AARCH64_PREL64";
+        /// PC-relative 32-bit.
+        Prel32 = 261, "R_AARCH64_PREL32";
+        /// PC-relative 16-bit.
+        Prel16 = 262, "R_AARCH64_PREL16";
+        /// Dir. MOVZ imm. from bits 15:0.
+        MovwUabsG0 = 263, "R_AARCH64_MOVW_UABS_G0";
+        /// Likewise for MOVK; no check.
+        MovwUabsG0Nc = 264, "R_AARCH64_MOVW_UABS_G0_NC";
+        /// Dir. MOVZ imm. from bits 31:16.
+        MovwUabsG1 = 265, "R_AARCH64_MOVW_UABS_G1";
+        /// Likewise for MOVK; no check.
+        MovwUabsG1Nc = 266, "R_AARCH64_MOVW_UABS_G1_NC";
+        /// Dir. MOVZ imm. from bits 47:32.
+        MovwUabsG2 = 267, "R_AARCH64_MOVW_UABS_G2";
+        /// Likewise for MOVK; no check.
+        MovwUabsG2Nc = 268, "R_AARCH64_MOVW_UABS_G2_NC";
+        /// Dir. MOV{K,Z} imm. from 63:48.
+        MovwUabsG3 = 269, "R_AARCH64_MOVW_UABS_G3";
+        /// Dir. MOV{N,Z} imm. from 15:0.
+        MovwSabsG0 = 270, "R_AARCH64_MOVW_SABS_G0";
+        /// Dir. MOV{N,Z} imm. from 31:16.
+        MovwSabsG1 = 271, "R_AARCH64_MOVW_SABS_G1";
+        /// Dir. MOV{N,Z} imm. from 47:32.
+        MovwSabsG2 = 272, "R_AARCH64_MOVW_SABS_G2";
+        /// PC-rel. LD imm. from bits 20:2.
+        LdPrelLo19 = 273, "R_AARCH64_LD_PREL_LO19";
+        /// PC-rel. ADR imm. from bits 20:0.
+        AdrPrelLo21 = 274, "R_AARCH64_ADR_PREL_LO21";
+        /// Page-rel. ADRP imm. from 32:12.
+        AdrPrelPgHi21 = 275, "R_AARCH64_ADR_PREL_PG_HI21";
+        /// Likewise; no overflow check.
+        AdrPrelPgHi21Nc = 276, "R_AARCH64_ADR_PREL_PG_HI21_NC";
+        /// Dir. ADD imm. from bits 11:0.
+        AddAbsLo12Nc = 277, "R_AARCH64_ADD_ABS_LO12_NC";
+        /// Likewise for LD/ST; no check.
+        Ldst8AbsLo12Nc = 278, "R_AARCH64_LDST8_ABS_LO12_NC";
+        /// PC-rel. TBZ/TBNZ imm. from 15:2.
+        Tstbr14 = 279, "R_AARCH64_TSTBR14";
+        /// PC-rel. cond. br. imm. from 20:2.
+        Condbr19 = 280, "R_AARCH64_CONDBR19";
+        /// PC-rel. B imm. from bits 27:2.
+        Jump26 = 282, "R_AARCH64_JUMP26";
+        /// Likewise for CALL.
+        Call26 = 283, "R_AARCH64_CALL26";
+        /// Dir. ADD imm. from bits 11:1.
+        Ldst16AbsLo12Nc = 284, "R_AARCH64_LDST16_ABS_LO12_NC";
+        /// Likewise for bits 11:2.
+        Ldst32AbsLo12Nc = 285, "R_AARCH64_LDST32_ABS_LO12_NC";
+        /// Likewise for bits 11:3.
+        Ldst64AbsLo12Nc = 286, "R_AARCH64_LDST64_ABS_LO12_NC";
+        /// PC-rel. MOV{N,Z} imm. from 15:0.
+        MovwPrelG0 = 287, "R_AARCH64_MOVW_PREL_G0";
+        /// Likewise for MOVK; no check.
+        MovwPrelG0Nc = 288, "R_AARCH64_MOVW_PREL_G0_NC";
+        /// PC-rel. MOV{N,Z} imm. from 31:16.
+        MovwPrelG1 = 289, "R_AARCH64_MOVW_PREL_G1";
+        /// Likewise for MOVK; no check.
+        MovwPrelG1Nc = 290, "R_AARCH64_MOVW_PREL_G1_NC";
+        /// PC-rel. MOV{N,Z} imm. from 47:32.
+        MovwPrelG2 = 291, "R_AARCH64_MOVW_PREL_G2";
+        /// Likewise for MOVK; no check.
+        MovwPrelG2Nc = 292, "R_AARCH64_MOVW_PREL_G2_NC";
+        /// PC-rel. MOV{N,Z} imm. from 63:48.
+        MovwPrelG3 = 293, "R_AARCH64_MOVW_PREL_G3";
+        /// Dir. ADD imm. from bits 11:4.
+        Ldst128AbsLo12Nc = 299, "R_AARCH64_LDST128_ABS_LO12_NC";
+        /// GOT-rel. off. MOV{N,Z} imm. 15:0.
+        MovwGotoffG0 = 300, "R_AARCH64_MOVW_GOTOFF_G0";
+        /// Likewise for MOVK; no check.
+        MovwGotoffG0Nc = 301, "R_AARCH64_MOVW_GOTOFF_G0_NC";
+        /// GOT-rel. o. MOV{N,Z} imm. 31:16.
+        MovwGotoffG1 = 302, "R_AARCH64_MOVW_GOTOFF_G1";
+        /// Likewise for MOVK; no check.
+        MovwGotoffG1Nc = 303, "R_AARCH64_MOVW_GOTOFF_G1_NC";
+        /// GOT-rel. o. MOV{N,Z} imm. 47:32.
+        MovwGotoffG2 = 304, "R_AARCH64_MOVW_GOTOFF_G2";
+        /// Likewise for MOVK; no check.
+        MovwGotoffG2Nc = 305, "R_AARCH64_MOVW_GOTOFF_G2_NC";
+        /// GOT-rel. o. MOV{N,Z} imm. 63:48.
+        MovwGotoffG3 = 306, "R_AARCH64_MOVW_GOTOFF_G3";
+        /// GOT-relative 64-bit.
+        Gotrel64 = 307, "R_AARCH64_GOTREL64";
+        /// GOT-relative 32-bit.
+        Gotrel32 = 308, "R_AARCH64_GOTREL32";
+        /// PC-rel. GOT off. load imm. 20:2.
+        GotLdPrel19 = 309, "R_AARCH64_GOT_LD_PREL19";
+        /// GOT-rel. off. LD/ST imm. 14:3.
+        Ld64GotoffLo15 = 310, "R_AARCH64_LD64_GOTOFF_LO15";
+        /// P-page-rel. GOT off. ADRP 32:12.
+        AdrGotPage = 311, "R_AARCH64_ADR_GOT_PAGE";
+        /// Dir. GOT off. LD/ST imm. 11:3.
+        Ld64GotLo12Nc = 312, "R_AARCH64_LD64_GOT_LO12_NC";
+        /// GOT-page-rel. GOT off. LD/ST 14:3.
+        Ld64GotpageLo15 = 313, "R_AARCH64_LD64_GOTPAGE_LO15";
+        /// PC-relative ADR imm. 20:0.
+        TlsgdAdrPrel21 = 512, "R_AARCH64_TLSGD_ADR_PREL21";
+        /// Page-rel. ADRP imm. 32:12.
+        TlsgdAdrPage21 = 513, "R_AARCH64_TLSGD_ADR_PAGE21";
+        /// Direct ADD imm. from 11:0.
+        TlsgdAddLo12Nc = 514, "R_AARCH64_TLSGD_ADD_LO12_NC";
+        /// GOT-rel. MOV{N,Z} 31:16.
+        TlsgdMovwG1 = 515, "R_AARCH64_TLSGD_MOVW_G1";
+        /// GOT-rel. MOVK imm. 15:0.
+        TlsgdMovwG0Nc = 516, "R_AARCH64_TLSGD_MOVW_G0_NC";
+        /// Like 512; local dynamic model.
+        TlsldAdrPrel21 = 517, "R_AARCH64_TLSLD_ADR_PREL21";
+        /// Like 513; local dynamic model.
+        TlsldAdrPage21 = 518, "R_AARCH64_TLSLD_ADR_PAGE21";
+        /// Like 514; local dynamic model.
+        TlsldAddLo12Nc = 519, "R_AARCH64_TLSLD_ADD_LO12_NC";
+        /// Like 515; local dynamic model.
+        TlsldMovwG1 = 520, "R_AARCH64_TLSLD_MOVW_G1";
+        /// Like 516; local dynamic model.
+        TlsldMovwG0Nc = 521, "R_AARCH64_TLSLD_MOVW_G0_NC";
+        /// TLS PC-rel. load imm. 20:2.
+        TlsldLdPrel19 = 522, "R_AARCH64_TLSLD_LD_PREL19";
+        /// TLS DTP-rel. MOV{N,Z} 47:32.
+        TlsldMovwDtprelG2 = 523, "R_AARCH64_TLSLD_MOVW_DTPREL_G2";
+        /// TLS DTP-rel. MOV{N,Z} 31:16.
+        TlsldMovwDtprelG1 = 524, "R_AARCH64_TLSLD_MOVW_DTPREL_G1";
+        /// Likewise; MOVK; no check.
+        TlsldMovwDtprelG1Nc = 525, "R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC";
+        /// TLS DTP-rel. MOV{N,Z} 15:0.
+        TlsldMovwDtprelG0 = 526, "R_AARCH64_TLSLD_MOVW_DTPREL_G0";
+        /// Likewise; MOVK; no check.
+        TlsldMovwDtprelG0Nc = 527, "R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC";
+        /// DTP-rel. ADD imm. from 23:12.
+        TlsldAddDtprelHi12 = 528, "R_AARCH64_TLSLD_ADD_DTPREL_HI12";
+        /// DTP-rel. ADD imm. from 11:0.
+        TlsldAddDtprelLo12 = 529, "R_AARCH64_TLSLD_ADD_DTPREL_LO12";
+        /// Likewise; no ovfl. check.
+        TlsldAddDtprelLo12Nc = 530, "R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC";
+        /// DTP-rel. LD/ST imm. 11:0.
+        TlsldLdst8DtprelLo12 = 531, "R_AARCH64_TLSLD_LDST8_DTPREL_LO12";
+        /// Likewise; no check.
+        TlsldLdst8DtprelLo12Nc = 532, "R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC";
+        /// DTP-rel. LD/ST imm. 11:1.
+        TlsldLdst16DtprelLo12 = 533, "R_AARCH64_TLSLD_LDST16_DTPREL_LO12";
+        /// Likewise; no check.
+        TlsldLdst16DtprelLo12Nc = 534, "R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC";
+        /// DTP-rel. LD/ST imm. 11:2.
+        TlsldLdst32DtprelLo12 = 535, "R_AARCH64_TLSLD_LDST32_DTPREL_LO12";
+        /// Likewise; no check.
+        TlsldLdst32DtprelLo12Nc = 536, "R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC";
+        /// DTP-rel. LD/ST imm. 11:3.
+        TlsldLdst64DtprelLo12 = 537, "R_AARCH64_TLSLD_LDST64_DTPREL_LO12";
+        /// Likewise; no check.
+        TlsldLdst64DtprelLo12Nc = 538, "R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC";
+        /// GOT-rel. MOV{N,Z} 31:16.
+        TlsieMovwGottprelG1 = 539, "R_AARCH64_TLSIE_MOVW_GOTTPREL_G1";
+        /// GOT-rel. MOVK 15:0.
+        TlsieMovwGottprelG0Nc = 540, "R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC";
+        /// Page-rel. ADRP 32:12.
+        TlsieAdrGottprelPage21 = 541, "R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21";
+        /// Direct LD off. 11:3.
+        TlsieLd64GottprelLo12Nc = 542, "R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC";
+        /// PC-rel. load imm. 20:2.
+        TlsieLdGottprelPrel19 = 543, "R_AARCH64_TLSIE_LD_GOTTPREL_PREL19";
+        /// TLS TP-rel. MOV{N,Z} 47:32.
+        TlsleMovwTprelG2 = 544, "R_AARCH64_TLSLE_MOVW_TPREL_G2";
+        /// TLS TP-rel. MOV{N,Z} 31:16.
+        TlsleMovwTprelG1 = 545, "R_AARCH64_TLSLE_MOVW_TPREL_G1";
+        /// Likewise; MOVK; no check.
+        TlsleMovwTprelG1Nc = 546, "R_AARCH64_TLSLE_MOVW_TPREL_G1_NC";
+        /// TLS TP-rel. MOV{N,Z} 15:0.
+        TlsleMovwTprelG0 = 547, "R_AARCH64_TLSLE_MOVW_TPREL_G0";
+        /// Likewise; MOVK; no check.
+        TlsleMovwTprelG0Nc = 548, "R_AARCH64_TLSLE_MOVW_TPREL_G0_NC";
+        /// TP-rel. ADD imm. 23:12.
+        TlsleAddTprelHi12 = 549, "R_AARCH64_TLSLE_ADD_TPREL_HI12";
+        /// TP-rel. ADD imm. 11:0.
+        TlsleAddTprelLo12 = 550, "R_AARCH64_TLSLE_ADD_TPREL_LO12";
+        /// Likewise; no ovfl. check.
+        TlsleAddTprelLo12Nc = 551, "R_AARCH64_TLSLE_ADD_TPREL_LO12_NC";
+        /// TP-rel. LD/ST off. 11:0.
+        TlsleLdst8TprelLo12 = 552, "R_AARCH64_TLSLE_LDST8_TPREL_LO12";
+        /// Likewise; no ovfl. check.
+        TlsleLdst8TprelLo12Nc = 553, "R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC";
+        /// TP-rel. LD/ST off. 11:1.
+        TlsleLdst16TprelLo12 = 554, "R_AARCH64_TLSLE_LDST16_TPREL_LO12";
+        /// Likewise; no check.
+        TlsleLdst16TprelLo12Nc = 555, "R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC";
+        /// TP-rel. LD/ST off. 11:2.
+        TlsleLdst32TprelLo12 = 556, "R_AARCH64_TLSLE_LDST32_TPREL_LO12";
+        /// Likewise; no check.
+        TlsleLdst32TprelLo12Nc = 557, "R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC";
+        /// TP-rel. LD/ST off. 11:3.
+        TlsleLdst64TprelLo12 = 558, "R_AARCH64_TLSLE_LDST64_TPREL_LO12";
+        /// Likewise; no check.
+        TlsleLdst64TprelLo12Nc = 559, "R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC";
+        /// PC-rel. load immediate 20:2.
+        TlsdescLdPrel19 = 560, "R_AARCH64_TLSDESC_LD_PREL19";
+        /// PC-rel. ADR immediate 20:0.
+        TlsdescAdrPrel21 = 561, "R_AARCH64_TLSDESC_ADR_PREL21";
+        /// Page-rel. ADRP imm. 32:12.
+        TlsdescAdrPage21 = 562, "R_AARCH64_TLSDESC_ADR_PAGE21";
+        /// Direct LD off. from 11:3.
+        TlsdescLd64Lo12 = 563, "R_AARCH64_TLSDESC_LD64_LO12";
+        /// Direct ADD imm. from 11:0.
+        TlsdescAddLo12 = 564, "R_AARCH64_TLSDESC_ADD_LO12";
+        /// GOT-rel. MOV{N,Z} imm. 31:16.
+        TlsdescOffG1 = 565, "R_AARCH64_TLSDESC_OFF_G1";
+        /// GOT-rel. MOVK imm. 15:0; no ck.
+        TlsdescOffG0Nc = 566, "R_AARCH64_TLSDESC_OFF_G0_NC";
+        /// Relax LDR.
+        TlsdescLdr = 567, "R_AARCH64_TLSDESC_LDR";
+        /// Relax ADD.
+        TlsdescAdd = 568, "R_AARCH64_TLSDESC_ADD";
+        /// Relax BLR.
+        TlsdescCall = 569, "R_AARCH64_TLSDESC_CALL";
+        /// TP-rel. LD/ST off. 11:4.
+        TlsleLdst128TprelLo12 = 570, "R_AARCH64_TLSLE_LDST128_TPREL_LO12";
+        /// Likewise; no check.
+        TlsleLdst128TprelLo12Nc = 571, "R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC";
+        /// DTP-rel. LD/ST imm. 11:4.
+        TlsldLdst128DtprelLo12 = 572, "R_AARCH64_TLSLD_LDST128_DTPREL_LO12";
+        /// Likewise; no check.
+        TlsldLdst128DtprelLo12Nc = 573, "R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC";
+        /// Copy symbol at runtime.
+        Copy = 1024, "R_AARCH64_COPY";
+        /// Create GOT entry.
+        GlobDat = 1025, "R_AARCH64_GLOB_DAT";
+        /// Create PLT entry.
+        JumpSlot = 1026, "R_AARCH64_JUMP_SLOT";
+        /// Adjust by program base.
+        Relative = 1027, "R_AARCH64_RELATIVE";
+        /// Module number, 64 bit.
+        TlsDtpmod = 1028, "R_AARCH64_TLS_DTPMOD";
+        /// Module-relative offset, 64 bit.
+        TlsDtprel = 1029, "R_AARCH64_TLS_DTPREL";
+        /// TP-relative offset, 64 bit.
+        TlsTprel = 1030, "R_AARCH64_TLS_TPREL";
+        /// TLS Descriptor.
+        Tlsdesc = 1031, "R_AARCH64_TLSDESC";
+        /// STT_GNU_IFUNC relocation.
+        Irelative = 1032, "R_AARCH64_IRELATIVE";
+    }
+}
+
+relocation_types! {
+    /// ARM relocation types.
+    RelocationArm {
+        /// No reloc.
+        None = 0, "R_ARM_NONE";
+        /// Deprecated PC relative 26 bit branch.
+        Pc24 = 1, "R_ARM_PC24";
+        /// Direct 32 bit.
+        Abs32 = 2, "R_ARM_ABS32";
+        /// PC relative 32 bit.
+        Rel32 = 3, "R_ARM_REL32";
+        /// PC relative (LDR, STR, LDRB, STRB).
+        LdrPcG0 = 4, "R_ARM_LDR_PC_G0";
+        /// Direct 16 bit.
+        Abs16 = 5, "R_ARM_ABS16";
+        /// Direct 12 bit.
+        Abs12 = 6, "R_ARM_ABS12";
+        /// Direct & 0x7C (LDR, STR).
+        ThmAbs5 = 7, "R_ARM_THM_ABS5";
+        /// Direct 8 bit.
+        Abs8 = 8, "R_ARM_ABS8";
+        Sbrel32 = 9, "R_ARM_SBREL32";
+        /// PC relative 24 bit (Thumb32 BL).
+        ThmCall = 10, "R_ARM_THM_CALL";
+        /// PC relative & 0x3FC (Thumb16 LDR, ADD, ADR).
+        ThmPc8 = 11, "R_ARM_THM_PC8";
+        BrelAdj = 12, "R_ARM_BREL_ADJ";
+        /// TLS descriptor.
+        TlsDesc = 13, "R_ARM_TLS_DESC";
+        /// Reserved.
+        ThmSwi8 = 14, "R_ARM_THM_SWI8";
+        /// Reserved.
+        Xpc25 = 15, "R_ARM_XPC25";
+        /// Reserved.
+        ThmXpc22 = 16, "R_ARM_THM_XPC22";
+        /// ID of module containing symbol.
+        TlsDtpmod32 = 17, "R_ARM_TLS_DTPMOD32";
+        /// Offset in TLS block.
+        TlsDtpoff32 = 18, "R_ARM_TLS_DTPOFF32";
+        /// Offset in static TLS block.
+        TlsTpoff32 = 19, "R_ARM_TLS_TPOFF32";
+        /// Copy symbol at runtime.
+        Copy = 20, "R_ARM_COPY";
+        /// Create GOT entry.
+        GlobDat = 21, "R_ARM_GLOB_DAT";
+        /// Create PLT entry.
+        JumpSlot = 22, "R_ARM_JUMP_SLOT";
+        /// Adjust by program base.
+        Relative = 23, "R_ARM_RELATIVE";
+        /// 32 bit offset to GOT.
+        Gotoff32 = 24, "R_ARM_GOTOFF32";
+        /// 32 bit PC relative offset to GOT.
+        BasePrel = 25, "R_ARM_BASE_PREL";
+        /// 32 bit GOT entry.
+        GotBrel = 26, "R_ARM_GOT_BREL";
+        /// Deprecated, 32 bit PLT address.
+        Plt32 = 27, "R_ARM_PLT32";
+        /// PC relative 24 bit (BL, BLX).
+        Call = 28, "R_ARM_CALL";
+        /// PC relative 24 bit (B, BL<cond>).
+        Jump24 = 29, "R_ARM_JUMP24";
+        /// PC relative 24 bit (Thumb32 B.W).
+        ThmJump24 = 30, "R_ARM_THM_JUMP24";
+        /// Adjust by program base.
+        BaseAbs = 31, "R_ARM_BASE_ABS";
+        /// Obsolete.
+        AluPcrel70 = 32, "R_ARM_ALU_PCREL7_0";
+        /// Obsolete.
+        AluPcrel158 = 33, "R_ARM_ALU_PCREL15_8";
+        /// Obsolete.
+        AluPcrel2315 = 34, "R_ARM_ALU_PCREL23_15";
+        /// Deprecated, prog. base relative.
+        LdrSbrel110 = 35, "R_ARM_LDR_SBREL_11_0";
+        /// Deprecated, prog. base relative.
+        AluSbrel1912 = 36, "R_ARM_ALU_SBREL_19_12";
+        /// Deprecated, prog. base relative.
+        AluSbrel2720 = 37, "R_ARM_ALU_SBREL_27_20";
+        Target1 = 38, "R_ARM_TARGET1";
+        /// Program base relative.
+        Sbrel31 = 39, "R_ARM_SBREL31";
+        V4bx = 40, "R_ARM_V4BX";
+        Target2 = 41, "R_ARM_TARGET2";
+        /// 32 bit PC relative.
+        Prel31 = 42, "R_ARM_PREL31";
+        /// Direct 16-bit (MOVW).
+        MovwAbsNc = 43, "R_ARM_MOVW_ABS_NC";
+        /// Direct high 16-bit (MOVT).
+        MovtAbs = 44, "R_ARM_MOVT_ABS";
+        /// PC relative 16-bit (MOVW).
+        MovwPrelNc = 45, "R_ARM_MOVW_PREL_NC";
+        /// PC relative (MOVT).
+        MovtPrel = 46, "R_ARM_MOVT_PREL";
+        /// Direct 16 bit (Thumb32 MOVW).
+        ThmMovwAbsNc = 47, "R_ARM_THM_MOVW_ABS_NC";
+        /// Direct high 16 bit (Thumb32 MOVT).
+        ThmMovtAbs = 48, "R_ARM_THM_MOVT_ABS";
+        /// PC relative 16 bit (Thumb32 MOVW).
+        ThmMovwPrelNc = 49, "R_ARM_THM_MOVW_PREL_NC";
+        /// PC relative high 16 bit (Thumb32 MOVT).
+        ThmMovtPrel = 50, "R_ARM_THM_MOVT_PREL";
+        /// PC relative 20 bit (Thumb32 B<cond>.W).
+        ThmJump19 = 51, "R_ARM_THM_JUMP19";
+        /// PC relative X & 0x7E (Thumb16 CBZ, CBNZ).
+        ThmJump6 = 52, "R_ARM_THM_JUMP6";
+        /// PC relative 12 bit (Thumb32 ADR.W).
+        ThmAluPrel110 = 53, "R_ARM_THM_ALU_PREL_11_0";
+        /// PC relative 12 bit (Thumb32 LDR{D,SB,H,SH}).
+        ThmPc12 = 54, "R_ARM_THM_PC12";
+        /// Direct 32-bit.
+        Abs32Noi = 55, "R_ARM_ABS32_NOI";
+        /// PC relative 32-bit.
+        Rel32Noi = 56, "R_ARM_REL32_NOI";
+        /// PC relative (ADD, SUB).
+        AluPcG0Nc = 57, "R_ARM_ALU_PC_G0_NC";
+        /// PC relative (ADD, SUB).
+        AluPcG0 = 58, "R_ARM_ALU_PC_G0";
+        /// PC relative (ADD, SUB).
+        AluPcG1Nc = 59, "R_ARM_ALU_PC_G1_NC";
+        /// PC relative (ADD, SUB).
+        AluPcG1 = 60, "R_ARM_ALU_PC_G1";
+        /// PC relative (ADD, SUB).
+        AluPcG2 = 61, "R_ARM_ALU_PC_G2";
+        /// PC relative (LDR,STR,LDRB,STRB).
+        LdrPcG1 = 62, "R_ARM_LDR_PC_G1";
+        /// PC relative (LDR,STR,LDRB,STRB).
+        LdrPcG2 = 63, "R_ARM_LDR_PC_G2";
+        /// PC relative (STR{D,H}, LDR{D,SB,H,SH}).
+        LdrsPcG0 = 64, "R_ARM_LDRS_PC_G0";
+        /// PC relative (STR{D,H}, LDR{D,SB,H,SH}).
+        LdrsPcG1 = 65, "R_ARM_LDRS_PC_G1";
+        /// PC relative (STR{D,H}, LDR{D,SB,H,SH}).
+        LdrsPcG2 = 66, "R_ARM_LDRS_PC_G2";
+        /// PC relative (LDC, STC).
+        LdcPcG0 = 67, "R_ARM_LDC_PC_G0";
+        /// PC relative (LDC, STC).
+        LdcPcG1 = 68, "R_ARM_LDC_PC_G1";
+        /// PC relative (LDC, STC).
+        LdcPcG2 = 69, "R_ARM_LDC_PC_G2";
+        /// Program base relative (ADD,SUB).
+        AluSbG0Nc = 70, "R_ARM_ALU_SB_G0_NC";
+        /// Program base relative (ADD,SUB).
+        AluSbG0 = 71, "R_ARM_ALU_SB_G0";
+        /// Program base relative (ADD,SUB).
+        AluSbG1Nc = 72, "R_ARM_ALU_SB_G1_NC";
+        /// Program base relative (ADD,SUB).
+        AluSbG1 = 73, "R_ARM_ALU_SB_G1";
+        /// Program base relative (ADD,SUB).
+        AluSbG2 = 74, "R_ARM_ALU_SB_G2";
+        /// Program base relative (LDR, STR, LDRB, STRB).
+        LdrSbG0 = 75, "R_ARM_LDR_SB_G0";
+        /// Program base relative (LDR, STR, LDRB, STRB).
+        LdrSbG1 = 76, "R_ARM_LDR_SB_G1";
+        /// Program base relative (LDR, STR, LDRB, STRB).
+        LdrSbG2 = 77, "R_ARM_LDR_SB_G2";
+        /// Program base relative (LDR, STR, LDRB, STRB).
+        LdrsSbG0 = 78, "R_ARM_LDRS_SB_G0";
+        /// Program base relative (LDR, STR, LDRB, STRB).
+        LdrsSbG1 = 79, "R_ARM_LDRS_SB_G1";
+        /// Program base relative (LDR, STR, LDRB, STRB).
+        LdrsSbG2 = 80, "R_ARM_LDRS_SB_G2";
+        /// Program base relative (LDC,STC).
+        LdcSbG0 = 81, "R_ARM_LDC_SB_G0";
+        /// Program base relative (LDC,STC).
+        LdcSbG1 = 82, "R_ARM_LDC_SB_G1";
+        /// Program base relative (LDC,STC).
+        LdcSbG2 = 83, "R_ARM_LDC_SB_G2";
+        /// Program base relative 16 bit (MOVW).
+        MovwBrelNc = 84, "R_ARM_MOVW_BREL_NC";
+        /// Program base relative high 16 bit (MOVT).
+        MovtBrel = 85, "R_ARM_MOVT_BREL";
+        /// Program base relative 16 bit (MOVW).
+        MovwBrel = 86, "R_ARM_MOVW_BREL";
+        /// Program base relative 16 bit (Thumb32 MOVW).
+        ThmMovwBrelNc = 87, "R_ARM_THM_MOVW_BREL_NC";
+        /// Program base relative high 16 bit (Thumb32 MOVT).
+        ThmMovtBrel = 88, "R_ARM_THM_MOVT_BREL";
+        /// Program base relative 16 bit (Thumb32 MOVW).
+        ThmMovwBrel = 89, "R_ARM_THM_MOVW_BREL";
+        TlsGotdesc = 90, "R_ARM_TLS_GOTDESC";
+        TlsCall = 91, "R_ARM_TLS_CALL";
+        /// TLS relaxation.
+        TlsDescseq = 92, "R_ARM_TLS_DESCSEQ";
+        ThmTlsCall = 93, "R_ARM_THM_TLS_CALL";
+        Plt32Abs = 94, "R_ARM_PLT32_ABS";
+        /// GOT entry.
+        GotAbs = 95, "R_ARM_GOT_ABS";
+        /// PC relative GOT entry.
+        GotPrel = 96, "R_ARM_GOT_PREL";
+        /// GOT entry relative to GOT origin (LDR).
+        GotBrel12 = 97, "R_ARM_GOT_BREL12";
+        /// 12 bit, GOT entry relative to GOT origin (LDR, STR).
+        Gotoff12 = 98, "R_ARM_GOTOFF12";
+        Gotrelax = 99, "R_ARM_GOTRELAX";
+        GnuVtentry = 100, "R_ARM_GNU_VTENTRY";
+        GnuVtinherit = 101, "R_ARM_GNU_VTINHERIT";
+        /// PC relative & 0xFFE (Thumb16 B).
+        ThmJump11 = 102, "R_ARM_THM_JUMP11";
+        /// PC relative & 0x1FE (Thumb16 B/B<cond>).
+        ThmJump8 = 103, "R_ARM_THM_JUMP8";
+        /// PC-rel 32 bit for global dynamic thread local data.
+        TlsGd32 = 104, "R_ARM_TLS_GD32";
+        /// PC-rel 32 bit for local dynamic thread local data.
+        TlsLdm32 = 105, "R_ARM_TLS_LDM32";
+        /// 32 bit offset relative to TLS block.
+        TlsLdo32 = 106, "R_ARM_TLS_LDO32";
+        /// PC-rel 32 bit for GOT entry of static TLS block offset.
+        TlsIe32 = 107, "R_ARM_TLS_IE32";
+        /// 32 bit offset relative to static TLS block.
+        TlsLe32 = 108, "R_ARM_TLS_LE32";
+        /// 12 bit relative to TLS block (LDR, STR).
+        TlsLdo12 = 109, "R_ARM_TLS_LDO12";
+        /// 12 bit relative to static TLS block (LDR, STR).
+        TlsLe12 = 110, "R_ARM_TLS_LE12";
+        /// 12 bit GOT entry relative to GOT origin (LDR).
+        TlsIe12gp = 111, "R_ARM_TLS_IE12GP";
+        /// Obsolete.
+        MeToo = 128, "R_ARM_ME_TOO";
+        ThmTlsDescseq = 129, "R_ARM_THM_TLS_DESCSEQ";
+        ThmTlsDescseq32 = 130, "R_ARM_THM_TLS_DESCSEQ32";
+        /// GOT entry relative to GOT origin, 12 bit (Thumb32 LDR).
+        ThmGotBrel12 = 131, "R_ARM_THM_GOT_BREL12";
+        ThmAluAbsG0Nc = 132, "R_ARM_THM_ALU_ABS_G0_NC";
+        ThmAluAbsG1Nc = 133, "R_ARM_THM_ALU_ABS_G1_NC";
+        ThmAluAbsG2Nc = 134, "R_ARM_THM_ALU_ABS_G2_NC";
+        ThmAluAbsG3Nc = 135, "R_ARM_THM_ALU_ABS_G3_NC";
+        ThmBf16 = 136, "R_ARM_THM_BF16";
+        ThmBf12 = 137, "R_ARM_THM_BF12";
+        ThmBf18 = 138, "R_ARM_THM_BF18";
+        Irelative = 160, "R_ARM_IRELATIVE";
+        Gotfuncdesc = 161, "R_ARM_GOTFUNCDESC";
+        Gotofffuncdesc = 162, "R_ARM_GOTOFFFUNCDESC";
+        Funcdesc = 163, "R_ARM_FUNCDESC";
+        FuncdescValue = 164, "R_ARM_FUNCDESC_VALUE";
+        TlsGd32Fdpic = 165, "R_ARM_TLS_GD32_FDPIC";
+        TlsLdm32Fdpic = 166, "R_ARM_TLS_LDM32_FDPIC";
+        TlsIe32Fdpic = 167, "R_ARM_TLS_IE32_FDPIC";
+        Rxpc25 = 249, "R_ARM_RXPC25";
+        Rsbrel32 = 250, "R_ARM_RSBREL32";
+        ThmRpc22 = 251, "R_ARM_THM_RPC22";
+        Rrel32 = 252, "R_ARM_RREL32";
+        Rabs32 = 253, "R_ARM_RABS32";
+        Rpc24 = 254, "R_ARM_RPC24";
+        Rbase = 255, "R_ARM_RBASE";
+    }
+}
+
+relocation_types! {
+    /// RISC-V relocation types.
+    RelocationRiscv {
+        None = 0, "R_RISCV_NONE";
+        Data32 = 1, "R_RISCV_32";
+        Data64 = 2, "R_RISCV_64";
+        Relative = 3, "R_RISCV_RELATIVE";
+        Copy = 4, "R_RISCV_COPY";
+        JumpSlot = 5, "R_RISCV_JUMP_SLOT";
+        TlsDtpmod32 = 6, "R_RISCV_TLS_DTPMOD32";
+        TlsDtpmod64 = 7, "R_RISCV_TLS_DTPMOD64";
+        TlsDtprel32 = 8, "R_RISCV_TLS_DTPREL32";
+        TlsDtprel64 = 9, "R_RISCV_TLS_DTPREL64";
+        TlsTprel32 = 10, "R_RISCV_TLS_TPREL32";
+        TlsTprel64 = 11, "R_RISCV_TLS_TPREL64";
+        Tlsdesc = 12, "R_RISCV_TLSDESC";
+        Branch = 16, "R_RISCV_BRANCH";
+        Jal = 17, "R_RISCV_JAL";
+        Call = 18, "R_RISCV_CALL";
+        CallPlt = 19, "R_RISCV_CALL_PLT";
+        GotHi20 = 20, "R_RISCV_GOT_HI20";
+        TlsGotHi20 = 21, "R_RISCV_TLS_GOT_HI20";
+        TlsGdHi20 = 22, "R_RISCV_TLS_GD_HI20";
+        PcrelHi20 = 23, "R_RISCV_PCREL_HI20";
+        PcrelLo12I = 24, "R_RISCV_PCREL_LO12_I";
+        PcrelLo12S = 25, "R_RISCV_PCREL_LO12_S";
+        Hi20 = 26, "R_RISCV_HI20";
+        Lo12I = 27, "R_RISCV_LO12_I";
+        Lo12S = 28, "R_RISCV_LO12_S";
+        TprelHi20 = 29, "R_RISCV_TPREL_HI20";
+        TprelLo12I = 30, "R_RISCV_TPREL_LO12_I";
+        TprelLo12S = 31, "R_RISCV_TPREL_LO12_S";
+        TprelAdd = 32, "R_RISCV_TPREL_ADD";
+        Add8 = 33, "R_RISCV_ADD8";
+        Add16 = 34, "R_RISCV_ADD16";
+        Add32 = 35, "R_RISCV_ADD32";
+        Add64 = 36, "R_RISCV_ADD64";
+        Sub8 = 37, "R_RISCV_SUB8";
+        Sub16 = 38, "R_RISCV_SUB16";
+        Sub32 = 39, "R_RISCV_SUB32";
+        Sub64 = 40, "R_RISCV_SUB64";
+        GnuVtinherit = 41, "R_RISCV_GNU_VTINHERIT";
+        GnuVtentry = 42, "R_RISCV_GNU_VTENTRY";
+        Align = 43, "R_RISCV_ALIGN";
+        RvcBranch = 44, "R_RISCV_RVC_BRANCH";
+        RvcJump = 45, "R_RISCV_RVC_JUMP";
+        RvcLui = 46, "R_RISCV_RVC_LUI";
+        GprelI = 47, "R_RISCV_GPREL_I";
+        GprelS = 48, "R_RISCV_GPREL_S";
+        TprelI = 49, "R_RISCV_TPREL_I";
+        TprelS = 50, "R_RISCV_TPREL_S";
+        Relax = 51, "R_RISCV_RELAX";
+        Sub6 = 52, "R_RISCV_SUB6";
+        Set6 = 53, "R_RISCV_SET6";
+        Set8 = 54, "R_RISCV_SET8";
+        Set16 = 55, "R_RISCV_SET16";
+        Set32 = 56, "R_RISCV_SET32";
+        Data32Pcrel = 57, "R_RISCV_32_PCREL";
+        Irelative = 58, "R_RISCV_IRELATIVE";
+        Plt32 = 59, "R_RISCV_PLT32";
+        SetUleb128 = 60, "R_RISCV_SET_ULEB128";
+        SubUleb128 = 61, "R_RISCV_SUB_ULEB128";
+        TlsdescHi20 = 62, "R_RISCV_TLSDESC_HI20";
+        TlsdescLoadLo12 = 63, "R_RISCV_TLSDESC_LOAD_LO12";
+        TlsdescAddLo12 = 64, "R_RISCV_TLSDESC_ADD_LO12";
+        TlsdescCall = 65, "R_RISCV_TLSDESC_CALL";
+    }
+}
+
+relocation_types! {
+    /// PowerPC64 relocation types.
+    RelocationPpc64 {
+        None = 0, "R_PPC64_NONE";
+        /// 32bit absolute address.
+        Addr32 = 1, "R_PPC64_ADDR32";
+        /// 26bit address, word aligned.
+        Addr24 = 2, "R_PPC64_ADDR24";
+        /// 16bit absolute address.
+        Addr16 = 3, "R_PPC64_ADDR16";
+        /// Lower 16bits of address.
+        Addr16Lo = 4, "R_PPC64_ADDR16_LO";
+        /// High 16bits of address.
+        Addr16Hi = 5, "R_PPC64_ADDR16_HI";
+        /// Adjusted high 16bits.
+        Addr16Ha = 6, "R_PPC64_ADDR16_HA";
+        /// 16bit address, word aligned.
+        Addr14 = 7, "R_PPC64_ADDR14";
+        Addr14Brtaken = 8, "R_PPC64_ADDR14_BRTAKEN";
+        Addr14Brntaken = 9, "R_PPC64_ADDR14_BRNTAKEN";
+        /// PC-rel. 26 bit, word aligned.
+        Rel24 = 10, "R_PPC64_REL24";
+        /// PC relative 16 bit.
+        Rel14 = 11, "R_PPC64_REL14";
+        Rel14Brtaken = 12, "R_PPC64_REL14_BRTAKEN";
+        Rel14Brntaken = 13, "R_PPC64_REL14_BRNTAKEN";
+        Got16 = 14, "R_PPC64_GOT16";
+        Got16Lo = 15, "R_PPC64_GOT16_LO";
+        Got16Hi = 16, "R_PPC64_GOT16_HI";
+        Got16Ha = 17, "R_PPC64_GOT16_HA";
+        Copy = 19, "R_PPC64_COPY";
+        GlobDat = 20, "R_PPC64_GLOB_DAT";
+        JmpSlot = 21, "R_PPC64_JMP_SLOT";
+        Relative = 22, "R_PPC64_RELATIVE";
+        Uaddr32 = 24, "R_PPC64_UADDR32";
+        Uaddr16 = 25, "R_PPC64_UADDR16";
+        Rel32 = 26, "R_PPC64_REL32";
+        Plt32 = 27, "R_PPC64_PLT32";
+        Pltrel32 = 28, "R_PPC64_PLTREL32";
+        Plt16Lo = 29, "R_PPC64_PLT16_LO";
+        Plt16Hi = 30, "R_PPC64_PLT16_HI";
+        Plt16Ha = 31, "R_PPC64_PLT16_HA";
+        Sectoff = 33, "R_PPC64_SECTOFF";
+        SectoffLo = 34, "R_PPC64_SECTOFF_LO";
+        SectoffHi = 35, "R_PPC64_SECTOFF_HI";
+        SectoffHa = 36, "R_PPC64_SECTOFF_HA";
+        /// Word30 (S + A - P) >> 2.
+        Addr30 = 37, "R_PPC64_ADDR30";
+        /// Doubleword64 S + A.
+        Addr64 = 38, "R_PPC64_ADDR64";
+        /// Half16 #higher(S + A).
+        Addr16Higher = 39, "R_PPC64_ADDR16_HIGHER";
+        /// Half16 #highera(S + A).
+        Addr16Highera = 40, "R_PPC64_ADDR16_HIGHERA";
+        /// Half16 #highest(S + A).
+        Addr16Highest = 41, "R_PPC64_ADDR16_HIGHEST";
+        /// Half16 #highesta(S + A).
+        Addr16Highesta = 42, "R_PPC64_ADDR16_HIGHESTA";
+        /// Doubleword64 S + A.
+        Uaddr64 = 43, "R_PPC64_UADDR64";
+        /// Doubleword64 S + A - P.
+        Rel64 = 44, "R_PPC64_REL64";
+        /// Doubleword64 L + A.
+        Plt64 = 45, "R_PPC64_PLT64";
+        /// Doubleword64 L + A - P.
+        Pltrel64 = 46, "R_PPC64_PLTREL64";
+        /// Half16* S + A - .TOC.
+        Toc16 = 47, "R_PPC64_TOC16";
+        /// Half16 #lo(S + A - .TOC.).
+        Toc16Lo = 48, "R_PPC64_TOC16_LO";
+        /// Half16 #hi(S + A - .TOC.).
+        Toc16Hi = 49, "R_PPC64_TOC16_HI";
+        /// Half16 #ha(S + A - .TOC.).
+        Toc16Ha = 50, "R_PPC64_TOC16_HA";
+        /// Doubleword64 .TOC.
+        Toc = 51, "R_PPC64_TOC";
+        /// Half16* M + A.
+        Pltgot16 = 52, "R_PPC64_PLTGOT16";
+        /// Half16 #lo(M + A).
+        Pltgot16Lo = 53, "R_PPC64_PLTGOT16_LO";
+        /// Half16 #hi(M + A).
+        Pltgot16Hi = 54, "R_PPC64_PLTGOT16_HI";
+        /// Half16 #ha(M + A).
+        Pltgot16Ha = 55, "R_PPC64_PLTGOT16_HA";
+        /// Half16ds* (S + A) >> 2.
+        Addr16Ds = 56, "R_PPC64_ADDR16_DS";
+        /// Half16ds #lo(S + A) >> 2.
+        Addr16LoDs = 57, "R_PPC64_ADDR16_LO_DS";
+        /// Half16ds* (G + A) >> 2.
+        Got16Ds = 58, "R_PPC64_GOT16_DS";
+        /// Half16ds #lo(G + A) >> 2.
+        Got16LoDs = 59, "R_PPC64_GOT16_LO_DS";
+        /// Half16ds #lo(L + A) >> 2.
+        Plt16LoDs = 60, "R_PPC64_PLT16_LO_DS";
+        /// Half16ds* (R + A) >> 2.
+        SectoffDs = 61, "R_PPC64_SECTOFF_DS";
+        /// Half16ds #lo(R + A) >> 2.
+        SectoffLoDs = 62, "R_PPC64_SECTOFF_LO_DS";
+        /// Half16ds* (S + A - .TOC.) >> 2.
+        Toc16Ds = 63, "R_PPC64_TOC16_DS";
+        /// Half16ds #lo(S + A - .TOC.) >> 2.
+        Toc16LoDs = 64, "R_PPC64_TOC16_LO_DS";
+        /// Half16ds* (M + A) >> 2.
+        Pltgot16Ds = 65, "R_PPC64_PLTGOT16_DS";
+        /// Half16ds #lo(M + A) >> 2.
+        Pltgot16LoDs = 66, "R_PPC64_PLTGOT16_LO_DS";
+        /// None (sym+add)@tls.
+        Tls = 67, "R_PPC64_TLS";
+        /// Doubleword64 (sym+add)@dtpmod.
+        Dtpmod64 = 68, "R_PPC64_DTPMOD64";
+        /// Half16* (sym+add)@tprel.
+        Tprel16 = 69, "R_PPC64_TPREL16";
+        /// Half16 (sym+add)@tprel@l.
+        Tprel16Lo = 70, "R_PPC64_TPREL16_LO";
+        /// Half16 (sym+add)@tprel@h.
+        Tprel16Hi = 71, "R_PPC64_TPREL16_HI";
+        /// Half16 (sym+add)@tprel@ha.
+        Tprel16Ha = 72, "R_PPC64_TPREL16_HA";
+        /// Doubleword64 (sym+add)@tprel.
+        Tprel64 = 73, "R_PPC64_TPREL64";
+        /// Half16* (sym+add)@dtprel.
+        Dtprel16 = 74, "R_PPC64_DTPREL16";
+        /// Half16 (sym+add)@dtprel@l.
+        Dtprel16Lo = 75, "R_PPC64_DTPREL16_LO";
+        /// Half16 (sym+add)@dtprel@h.
+        Dtprel16Hi = 76, "R_PPC64_DTPREL16_HI";
+        /// Half16 (sym+add)@dtprel@ha.
+        Dtprel16Ha = 77, "R_PPC64_DTPREL16_HA";
+        /// Doubleword64 (sym+add)@dtprel.
+        Dtprel64 = 78, "R_PPC64_DTPREL64";
+        /// Half16* (sym+add)@got@tlsgd.
+        GotTlsgd16 = 79, "R_PPC64_GOT_TLSGD16";
+        /// Half16 (sym+add)@got@tlsgd@l.
+        GotTlsgd16Lo = 80, "R_PPC64_GOT_TLSGD16_LO";
+        /// Half16 (sym+add)@got@tlsgd@h.
+        GotTlsgd16Hi = 81, "R_PPC64_GOT_TLSGD16_HI";
+        /// Half16 (sym+add)@got@tlsgd@ha.
+        GotTlsgd16Ha = 82, "R_PPC64_GOT_TLSGD16_HA";
+        /// Half16* (sym+add)@got@tlsld.
+        GotTlsld16 = 83, "R_PPC64_GOT_TLSLD16";
+        /// Half16 (sym+add)@got@tlsld@l.
+        GotTlsld16Lo = 84, "R_PPC64_GOT_TLSLD16_LO";
+        /// Half16 (sym+add)@got@tlsld@h.
+        GotTlsld16Hi = 85, "R_PPC64_GOT_TLSLD16_HI";
+        /// Half16 (sym+add)@got@tlsld@ha.
+        GotTlsld16Ha = 86, "R_PPC64_GOT_TLSLD16_HA";
+        /// Half16ds* (sym+add)@got@tprel.
+        GotTprel16Ds = 87, "R_PPC64_GOT_TPREL16_DS";
+        /// Half16ds (sym+add)@got@tprel@l.
+        GotTprel16LoDs = 88, "R_PPC64_GOT_TPREL16_LO_DS";
+        /// Half16 (sym+add)@got@tprel@h.
+        GotTprel16Hi = 89, "R_PPC64_GOT_TPREL16_HI";
+        /// Half16 (sym+add)@got@tprel@ha.
+        GotTprel16Ha = 90, "R_PPC64_GOT_TPREL16_HA";
+        /// Half16ds* (sym+add)@got@dtprel.
+        GotDtprel16Ds = 91, "R_PPC64_GOT_DTPREL16_DS";
+        /// Half16ds (sym+add)@got@dtprel@l.
+        GotDtprel16LoDs = 92, "R_PPC64_GOT_DTPREL16_LO_DS";
+        /// Half16 (sym+add)@got@dtprel@h.
+        GotDtprel16Hi = 93, "R_PPC64_GOT_DTPREL16_HI";
+        /// Half16 (sym+add)@got@dtprel@ha.
+        GotDtprel16Ha = 94, "R_PPC64_GOT_DTPREL16_HA";
+        /// Half16ds* (sym+add)@tprel.
+        Tprel16Ds = 95, "R_PPC64_TPREL16_DS";
+        /// Half16ds (sym+add)@tprel@l.
+        Tprel16LoDs = 96, "R_PPC64_TPREL16_LO_DS";
+        /// Half16 (sym+add)@tprel@higher.
+        Tprel16Higher = 97, "R_PPC64_TPREL16_HIGHER";
+        /// Half16 (sym+add)@tprel@highera.
+        Tprel16Highera = 98, "R_PPC64_TPREL16_HIGHERA";
+        /// Half16 (sym+add)@tprel@highest.
+        Tprel16Highest = 99, "R_PPC64_TPREL16_HIGHEST";
+        /// Half16 (sym+add)@tprel@highesta.
+        Tprel16Highesta = 100, "R_PPC64_TPREL16_HIGHESTA";
+        /// Half16ds* (sym+add)@dtprel.
+        Dtprel16Ds = 101, "R_PPC64_DTPREL16_DS";
+        /// Half16ds (sym+add)@dtprel@l.
+        Dtprel16LoDs = 102, "R_PPC64_DTPREL16_LO_DS";
+        /// Half16 (sym+add)@dtprel@higher.
+        Dtprel16Higher = 103, "R_PPC64_DTPREL16_HIGHER";
+        /// Half16 (sym+add)@dtprel@highera.
+        Dtprel16Highera = 104, "R_PPC64_DTPREL16_HIGHERA";
+        /// Half16 (sym+add)@dtprel@highest.
+        Dtprel16Highest = 105, "R_PPC64_DTPREL16_HIGHEST";
+        /// Half16 (sym+add)@dtprel@highesta.
+        Dtprel16Highesta = 106, "R_PPC64_DTPREL16_HIGHESTA";
+        /// None (sym+add)@tlsgd.
+        Tlsgd = 107, "R_PPC64_TLSGD";
+        /// None (sym+add)@tlsld.
+        Tlsld = 108, "R_PPC64_TLSLD";
+        /// None.
+        Tocsave = 109, "R_PPC64_TOCSAVE";
+        Addr16High = 110, "R_PPC64_ADDR16_HIGH";
+        Addr16Higha = 111, "R_PPC64_ADDR16_HIGHA";
+        Tprel16High = 112, "R_PPC64_TPREL16_HIGH";
+        Tprel16Higha = 113, "R_PPC64_TPREL16_HIGHA";
+        Dtprel16High = 114, "R_PPC64_DTPREL16_HIGH";
+        Dtprel16Higha = 115, "R_PPC64_DTPREL16_HIGHA";
+        Rel24Notoc = 116, "R_PPC64_REL24_NOTOC";
+        Addr64Local = 117, "R_PPC64_ADDR64_LOCAL";
+        Entry = 118, "R_PPC64_ENTRY";
+        Pltseq = 119, "R_PPC64_PLTSEQ";
+        Pltcall = 120, "R_PPC64_PLTCALL";
+        PltseqNotoc = 121, "R_PPC64_PLTSEQ_NOTOC";
+        PltcallNotoc = 122, "R_PPC64_PLTCALL_NOTOC";
+        PcrelOpt = 123, "R_PPC64_PCREL_OPT";
+        Rel24P9notoc = 124, "R_PPC64_REL24_P9NOTOC";
+        D34 = 128, "R_PPC64_D34";
+        D34Lo = 129, "R_PPC64_D34_LO";
+        D34Hi30 = 130, "R_PPC64_D34_HI30";
+        D34Ha30 = 131, "R_PPC64_D34_HA30";
+        Pcrel34 = 132, "R_PPC64_PCREL34";
+        GotPcrel34 = 133, "R_PPC64_GOT_PCREL34";
+        PltPcrel34 = 134, "R_PPC64_PLT_PCREL34";
+        PltPcrel34Notoc = 135, "R_PPC64_PLT_PCREL34_NOTOC";
+        Addr16Higher34 = 136, "R_PPC64_ADDR16_HIGHER34";
+        Addr16Highera34 = 137, "R_PPC64_ADDR16_HIGHERA34";
+        Addr16Highest34 = 138, "R_PPC64_ADDR16_HIGHEST34";
+        Addr16Highesta34 = 139, "R_PPC64_ADDR16_HIGHESTA34";
+        Rel16Higher34 = 140, "R_PPC64_REL16_HIGHER34";
+        Rel16Highera34 = 141, "R_PPC64_REL16_HIGHERA34";
+        Rel16Highest34 = 142, "R_PPC64_REL16_HIGHEST34";
+        Rel16Highesta34 = 143, "R_PPC64_REL16_HIGHESTA34";
+        D28 = 144, "R_PPC64_D28";
+        Pcrel28 = 145, "R_PPC64_PCREL28";
+        Tprel34 = 146, "R_PPC64_TPREL34";
+        Dtprel34 = 147, "R_PPC64_DTPREL34";
+        GotTlsgdPcrel34 = 148, "R_PPC64_GOT_TLSGD_PCREL34";
+        GotTlsldPcrel34 = 149, "R_PPC64_GOT_TLSLD_PCREL34";
+        GotTprelPcrel34 = 150, "R_PPC64_GOT_TPREL_PCREL34";
+        GotDtprelPcrel34 = 151, "R_PPC64_GOT_DTPREL_PCREL34";
+        Rel16High = 240, "R_PPC64_REL16_HIGH";
+        Rel16Higha = 241, "R_PPC64_REL16_HIGHA";
+        Rel16Higher = 242, "R_PPC64_REL16_HIGHER";
+        Rel16Highera = 243, "R_PPC64_REL16_HIGHERA";
+        Rel16Highest = 244, "R_PPC64_REL16_HIGHEST";
+        Rel16Highesta = 245, "R_PPC64_REL16_HIGHESTA";
+        Rel16dxHa = 246, "R_PPC64_REL16DX_HA";
+        JmpIrel = 247, "R_PPC64_JMP_IREL";
+        Irelative = 248, "R_PPC64_IRELATIVE";
+        /// Half16 (sym+add-.).
+        Rel16 = 249, "R_PPC64_REL16";
+        /// Half16 (sym+add-.)@l.
+        Rel16Lo = 250, "R_PPC64_REL16_LO";
+        /// Half16 (sym+add-.)@h.
+        Rel16Hi = 251, "R_PPC64_REL16_HI";
+        /// Half16 (sym+add-.)@ha.
+        Rel16Ha = 252, "R_PPC64_REL16_HA";
+        GnuVtinherit = 253, "R_PPC64_GNU_VTINHERIT";
+        GnuVtentry = 254, "R_PPC64_GNU_VTENTRY";
+    }
+}
+
+relocation_types! {
+    /// MIPS relocation types.
+    RelocationMips {
+        /// No reloc.
+        None = 0, "R_MIPS_NONE";
+        /// Direct 16 bit.
+        Data16 = 1, "R_MIPS_16";
+        /// Direct 32 bit.
+        Data32 = 2, "R_MIPS_32";
+        /// PC relative 32 bit.
+        Rel32 = 3, "R_MIPS_REL32";
+        /// Direct 26 bit shifted.
+        Data26 = 4, "R_MIPS_26";
+        /// High 16 bit.
+        Hi16 = 5, "R_MIPS_HI16";
+        /// Low 16 bit.
+        Lo16 = 6, "R_MIPS_LO16";
+        /// GP relative 16 bit.
+        Gprel16 = 7, "R_MIPS_GPREL16";
+        /// 16 bit literal entry.
+        Literal = 8, "R_MIPS_LITERAL";
+        /// 16 bit GOT entry.
+        Got16 = 9, "R_MIPS_GOT16";
+        /// PC relative 16 bit.
+        Pc16 = 10, "R_MIPS_PC16";
+        /// 16 bit GOT entry for function.
+        Call16 = 11, "R_MIPS_CALL16";
+        /// GP relative 32 bit.
+        Gprel32 = 12, "R_MIPS_GPREL32";
+        Shift5 = 16, "R_MIPS_SHIFT5";
+        Shift6 = 17, "R_MIPS_SHIFT6";
+        Data64 = 18, "R_MIPS_64";
+        GotDisp = 19, "R_MIPS_GOT_DISP";
+        GotPage = 20, "R_MIPS_GOT_PAGE";
+        GotOfst = 21, "R_MIPS_GOT_OFST";
+        GotHi16 = 22, "R_MIPS_GOT_HI16";
+        GotLo16 = 23, "R_MIPS_GOT_LO16";
+        Sub = 24, "R_MIPS_SUB";
+        InsertA = 25, "R_MIPS_INSERT_A";
+        InsertB = 26, "R_MIPS_INSERT_B";
+        Delete = 27, "R_MIPS_DELETE";
+        Higher = 28, "R_MIPS_HIGHER";
+        Highest = 29, "R_MIPS_HIGHEST";
+        CallHi16 = 30, "R_MIPS_CALL_HI16";
+        CallLo16 = 31, "R_MIPS_CALL_LO16";
+        ScnDisp = 32, "R_MIPS_SCN_DISP";
+        Rel16 = 33, "R_MIPS_REL16";
+        AddImmediate = 34, "R_MIPS_ADD_IMMEDIATE";
+        Pjump = 35, "R_MIPS_PJUMP";
+        Relgot = 36, "R_MIPS_RELGOT";
+        Jalr = 37, "R_MIPS_JALR";
+        /// Module number 32 bit.
+        TlsDtpmod32 = 38, "R_MIPS_TLS_DTPMOD32";
+        /// Module-relative offset 32 bit.
+        TlsDtprel32 = 39, "R_MIPS_TLS_DTPREL32";
+        /// Module number 64 bit.
+        TlsDtpmod64 = 40, "R_MIPS_TLS_DTPMOD64";
+        /// Module-relative offset 64 bit.
+        TlsDtprel64 = 41, "R_MIPS_TLS_DTPREL64";
+        /// 16 bit GOT offset for GD.
+        TlsGd = 42, "R_MIPS_TLS_GD";
+        /// 16 bit GOT offset for LDM.
+        TlsLdm = 43, "R_MIPS_TLS_LDM";
+        /// Module-relative offset, high 16 bits.
+        TlsDtprelHi16 = 44, "R_MIPS_TLS_DTPREL_HI16";
+        /// Module-relative offset, low 16 bits.
+        TlsDtprelLo16 = 45, "R_MIPS_TLS_DTPREL_LO16";
+        /// 16 bit GOT offset for IE.
+        TlsGottprel = 46, "R_MIPS_TLS_GOTTPREL";
+        /// TP-relative offset, 32 bit.
+        TlsTprel32 = 47, "R_MIPS_TLS_TPREL32";
+        /// TP-relative offset, 64 bit.
+        TlsTprel64 = 48, "R_MIPS_TLS_TPREL64";
+        /// TP-relative offset, high 16 bits.
+        TlsTprelHi16 = 49, "R_MIPS_TLS_TPREL_HI16";
+        /// TP-relative offset, low 16 bits.
+        TlsTprelLo16 = 50, "R_MIPS_TLS_TPREL_LO16";
+        GlobDat = 51, "R_MIPS_GLOB_DAT";
+        Pc21S2 = 60, "R_MIPS_PC21_S2";
+        Pc26S2 = 61, "R_MIPS_PC26_S2";
+        Pc18S3 = 62, "R_MIPS_PC18_S3";
+        Pc19S2 = 63, "R_MIPS_PC19_S2";
+        Pchi16 = 64, "R_MIPS_PCHI16";
+        Pclo16 = 65, "R_MIPS_PCLO16";
+        Copy = 126, "R_MIPS_COPY";
+        JumpSlot = 127, "R_MIPS_JUMP_SLOT";
+        Pc32 = 248, "R_MIPS_PC32";
+        Eh = 249, "R_MIPS_EH";
+        GnuRel16S2 = 250, "R_MIPS_GNU_REL16_S2";
+        GnuVtinherit = 253, "R_MIPS_GNU_VTINHERIT";
+        GnuVtentry = 254, "R_MIPS_GNU_VTENTRY";
+    }
+}
+
+relocation_types! {
+    /// s390 and s390x relocation types.
+    RelocationS390 {
+        /// No reloc.
+        None = 0, "R_390_NONE";
+        /// Direct 8 bit.
+        Data8 = 1, "R_390_8";
+        /// Direct 12 bit.
+        Data12 = 2, "R_390_12";
+        /// Direct 16 bit.
+        Data16 = 3, "R_390_16";
+        /// Direct 32 bit.
+        Data32 = 4, "R_390_32";
+        /// PC relative 32 bit.
+        Pc32 = 5, "R_390_PC32";
+        /// 12 bit GOT offset.
+        Got12 = 6, "R_390_GOT12";
+        /// 32 bit GOT offset.
+        Got32 = 7, "R_390_GOT32";
+        /// 32 bit PC relative PLT address.
+        Plt32 = 8, "R_390_PLT32";
+        /// Copy symbol at runtime.
+        Copy = 9, "R_390_COPY";
+        /// Create GOT entry.
+        GlobDat = 10, "R_390_GLOB_DAT";
+        /// Create PLT entry.
+        JmpSlot = 11, "R_390_JMP_SLOT";
+        /// Adjust by program base.
+        Relative = 12, "R_390_RELATIVE";
+        /// 32 bit offset to GOT.
+        Gotoff32 = 13, "R_390_GOTOFF32";
+        /// 32 bit PC relative offset to GOT.
+        Gotpc = 14, "R_390_GOTPC";
+        /// 16 bit GOT offset.
+        Got16 = 15, "R_390_GOT16";
+        /// PC relative 16 bit.
+        Pc16 = 16, "R_390_PC16";
+        /// PC relative 16 bit shifted by 1.
+        Pc16dbl = 17, "R_390_PC16DBL";
+        /// 16 bit PC rel. PLT shifted by 1.
+        Plt16dbl = 18, "R_390_PLT16DBL";
+        /// PC relative 32 bit shifted by 1.
+        Pc32dbl = 19, "R_390_PC32DBL";
+        /// 32 bit PC rel. PLT shifted by 1.
+        Plt32dbl = 20, "R_390_PLT32DBL";
+        /// 32 bit PC rel. GOT shifted by 1.
+        Gotpcdbl = 21, "R_390_GOTPCDBL";
+        /// Direct 64 bit.
+        Data64 = 22, "R_390_64";
+        /// PC relative 64 bit.
+        Pc64 = 23, "R_390_PC64";
+        /// 64 bit GOT offset.
+        Got64 = 24, "R_390_GOT64";
+        /// 64 bit PC relative PLT address.
+        Plt64 = 25, "R_390_PLT64";
+        /// 32 bit PC rel. to GOT entry >> 1.
+        Gotent = 26, "R_390_GOTENT";
+        /// 16 bit offset to GOT.
+        Gotoff16 = 27, "R_390_GOTOFF16";
+        /// 64 bit offset to GOT.
+        Gotoff64 = 28, "R_390_GOTOFF64";
+        /// 12 bit offset to jump slot.
+        Gotplt12 = 29, "R_390_GOTPLT12";
+        /// 16 bit offset to jump slot.
+        Gotplt16 = 30, "R_390_GOTPLT16";
+        /// 32 bit offset to jump slot.
+        Gotplt32 = 31, "R_390_GOTPLT32";
+        /// 64 bit offset to jump slot.
+        Gotplt64 = 32, "R_390_GOTPLT64";
+        /// 32 bit rel. offset to jump slot.
+        Gotpltent = 33, "R_390_GOTPLTENT";
+        /// 16 bit offset from GOT to PLT.
+        Pltoff16 = 34, "R_390_PLTOFF16";
+        /// 32 bit offset from GOT to PLT.
+        Pltoff32 = 35, "R_390_PLTOFF32";
+        /// 16 bit offset from GOT to PLT.
+        Pltoff64 = 36, "R_390_PLTOFF64";
+        /// Tag for load insn in TLS code.
+        TlsLoad = 37, "R_390_TLS_LOAD";
+        /// Tag for function call in general dynamic TLS code.
+        TlsGdcall = 38, "R_390_TLS_GDCALL";
+        /// Tag for function call in local dynamic TLS code.
+        TlsLdcall = 39, "R_390_TLS_LDCALL";
+        /// Direct 32 bit for general dynamic thread local data.
+        TlsGd32 = 40, "R_390_TLS_GD32";
+        /// Direct 64 bit for general dynamic thread local data.
+        TlsGd64 = 41, "R_390_TLS_GD64";
+        /// 12 bit GOT offset for static TLS block offset.
+        TlsGotie12 = 42, "R_390_TLS_GOTIE12";
+        /// 32 bit GOT offset for static TLS block offset.
+        TlsGotie32 = 43, "R_390_TLS_GOTIE32";
+        /// 64 bit GOT offset for static TLS block offset.
+        TlsGotie64 = 44, "R_390_TLS_GOTIE64";
+        /// Direct 32 bit for local dynamic thread local data in LE code.
+        TlsLdm32 = 45, "R_390_TLS_LDM32";
+        /// Direct 64 bit for local dynamic thread local data in LE code.
+        TlsLdm64 = 46, "R_390_TLS_LDM64";
+        /// 32 bit address of GOT entry for negated static TLS block offset.
+        TlsIe32 = 47, "R_390_TLS_IE32";
+        /// 64 bit address of GOT entry for negated static TLS block offset.
+        TlsIe64 = 48, "R_390_TLS_IE64";
+        /// 32 bit rel. offset to GOT entry for negated static TLS block offset.
+        TlsIeent = 49, "R_390_TLS_IEENT";
+        /// 32 bit negated offset relative to static TLS block.
+        TlsLe32 = 50, "R_390_TLS_LE32";
+        /// 64 bit negated offset relative to static TLS block.
+        TlsLe64 = 51, "R_390_TLS_LE64";
+        /// 32 bit offset relative to TLS block.
+        TlsLdo32 = 52, "R_390_TLS_LDO32";
+        /// 64 bit offset relative to TLS block.
+        TlsLdo64 = 53, "R_390_TLS_LDO64";
+        /// ID of module containing symbol.
+        TlsDtpmod = 54, "R_390_TLS_DTPMOD";
+        /// Offset in TLS block.
+        TlsDtpoff = 55, "R_390_TLS_DTPOFF";
+        /// Negated offset in static TLS block.
+        TlsTpoff = 56, "R_390_TLS_TPOFF";
+        /// Direct 20 bit.
+        Data20 = 57, "R_390_20";
+        /// 20 bit GOT offset.
+        Got20 = 58, "R_390_GOT20";
+        /// 20 bit offset to jump slot.
+        Gotplt20 = 59, "R_390_GOTPLT20";
+        /// 20 bit GOT offset for static TLS block offset.
+        TlsGotie20 = 60, "R_390_TLS_GOTIE20";
+        /// STT_GNU_IFUNC relocation.
+        Irelative = 61, "R_390_IRELATIVE";
+        Pc12dbl = 62, "R_390_PC12DBL";
+        Plt12dbl = 63, "R_390_PLT12DBL";
+        Pc24dbl = 64, "R_390_PC24DBL";
+        Plt24dbl = 65, "R_390_PLT24DBL";
+        GnuVtinherit = 250, "R_390_GNU_VTINHERIT";
+        GnuVtentry = 251, "R_390_GNU_VTENTRY";
+    }
+}
+
+relocation_types! {
+    /// LoongArch relocation types.
+    RelocationLoongArch {
+        None = 0, "R_LARCH_NONE";
+        Data32 = 1, "R_LARCH_32";
+        Data64 = 2, "R_LARCH_64";
+        Relative = 3, "R_LARCH_RELATIVE";
+        Copy = 4, "R_LARCH_COPY";
+        JumpSlot = 5, "R_LARCH_JUMP_SLOT";
+        TlsDtpmod32 = 6, "R_LARCH_TLS_DTPMOD32";
+        TlsDtpmod64 = 7, "R_LARCH_TLS_DTPMOD64";
+        TlsDtprel32 = 8, "R_LARCH_TLS_DTPREL32";
+        TlsDtprel64 = 9, "R_LARCH_TLS_DTPREL64";
+        TlsTprel32 = 10, "R_LARCH_TLS_TPREL32";
+        TlsTprel64 = 11, "R_LARCH_TLS_TPREL64";
+        Irelative = 12, "R_LARCH_IRELATIVE";
+        TlsDesc32 = 13, "R_LARCH_TLS_DESC32";
+        TlsDesc64 = 14, "R_LARCH_TLS_DESC64";
+        MarkLa = 20, "R_LARCH_MARK_LA";
+        MarkPcrel = 21, "R_LARCH_MARK_PCREL";
+        SopPushPcrel = 22, "R_LARCH_SOP_PUSH_PCREL";
+        SopPushAbsolute = 23, "R_LARCH_SOP_PUSH_ABSOLUTE";
+        SopPushDup = 24, "R_LARCH_SOP_PUSH_DUP";
+        SopPushGprel = 25, "R_LARCH_SOP_PUSH_GPREL";
+        SopPushTlsTprel = 26, "R_LARCH_SOP_PUSH_TLS_TPREL";
+        SopPushTlsGot = 27, "R_LARCH_SOP_PUSH_TLS_GOT";
+        SopPushTlsGd = 28, "R_LARCH_SOP_PUSH_TLS_GD";
+        SopPushPltPcrel = 29, "R_LARCH_SOP_PUSH_PLT_PCREL";
+        SopAssert = 30, "R_LARCH_SOP_ASSERT";
+        SopNot = 31, "R_LARCH_SOP_NOT";
+        SopSub = 32, "R_LARCH_SOP_SUB";
+        SopSl = 33, "R_LARCH_SOP_SL";
+        SopSr = 34, "R_LARCH_SOP_SR";
+        SopAdd = 35, "R_LARCH_SOP_ADD";
+        SopAnd = 36, "R_LARCH_SOP_AND";
+        SopIfElse = 37, "R_LARCH_SOP_IF_ELSE";
+        SopPop32S105 = 38, "R_LARCH_SOP_POP_32_S_10_5";
+        SopPop32U1012 = 39, "R_LARCH_SOP_POP_32_U_10_12";
+        SopPop32S1012 = 40, "R_LARCH_SOP_POP_32_S_10_12";
+        SopPop32S1016 = 41, "R_LARCH_SOP_POP_32_S_10_16";
+        SopPop32S1016S2 = 42, "R_LARCH_SOP_POP_32_S_10_16_S2";
+        SopPop32S520 = 43, "R_LARCH_SOP_POP_32_S_5_20";
+        SopPop32S051016S2 = 44, "R_LARCH_SOP_POP_32_S_0_5_10_16_S2";
+        SopPop32S0101016S2 = 45, "R_LARCH_SOP_POP_32_S_0_10_10_16_S2";
+        SopPop32U = 46, "R_LARCH_SOP_POP_32_U";
+        Add8 = 47, "R_LARCH_ADD8";
+        Add16 = 48, "R_LARCH_ADD16";
+        Add24 = 49, "R_LARCH_ADD24";
+        Add32 = 50, "R_LARCH_ADD32";
+        Add64 = 51, "R_LARCH_ADD64";
+        Sub8 = 52, "R_LARCH_SUB8";
+        Sub16 = 53, "R_LARCH_SUB16";
+        Sub24 = 54, "R_LARCH_SUB24";
+        Sub32 = 55, "R_LARCH_SUB32";
+        Sub64 = 56, "R_LARCH_SUB64";
+        GnuVtinherit = 57, "R_LARCH_GNU_VTINHERIT";
+        GnuVtentry = 58, "R_LARCH_GNU_VTENTRY";
+        B16 = 64, "R_LARCH_B16";
+        B21 = 65, "R_LARCH_B21";
+        B26 = 66, "R_LARCH_B26";
+        AbsHi20 = 67, "R_LARCH_ABS_HI20";
+        AbsLo12 = 68, "R_LARCH_ABS_LO12";
+        Abs64Lo20 = 69, "R_LARCH_ABS64_LO20";
+        Abs64Hi12 = 70, "R_LARCH_ABS64_HI12";
+        PcalaHi20 = 71, "R_LARCH_PCALA_HI20";
+        PcalaLo12 = 72, "R_LARCH_PCALA_LO12";
+        Pcala64Lo20 = 73, "R_LARCH_PCALA64_LO20";
+        Pcala64Hi12 = 74, "R_LARCH_PCALA64_HI12";
+        GotPcHi20 = 75, "R_LARCH_GOT_PC_HI20";
+        GotPcLo12 = 76, "R_LARCH_GOT_PC_LO12";
+        Got64PcLo20 = 77, "R_LARCH_GOT64_PC_LO20";
+        Got64PcHi12 = 78, "R_LARCH_GOT64_PC_HI12";
+        GotHi20 = 79, "R_LARCH_GOT_HI20";
+        GotLo12 = 80, "R_LARCH_GOT_LO12";
+        Got64Lo20 = 81, "R_LARCH_GOT64_LO20";
+        Got64Hi12 = 82, "R_LARCH_GOT64_HI12";
+        TlsLeHi20 = 83, "R_LARCH_TLS_LE_HI20";
+        TlsLeLo12 = 84, "R_LARCH_TLS_LE_LO12";
+        TlsLe64Lo20 = 85, "R_LARCH_TLS_LE64_LO20";
+        TlsLe64Hi12 = 86, "R_LARCH_TLS_LE64_HI12";
+        TlsIePcHi20 = 87, "R_LARCH_TLS_IE_PC_HI20";
+        TlsIePcLo12 = 88, "R_LARCH_TLS_IE_PC_LO12";
+        TlsIe64PcLo20 = 89, "R_LARCH_TLS_IE64_PC_LO20";
+        TlsIe64PcHi12 = 90, "R_LARCH_TLS_IE64_PC_HI12";
+        TlsIeHi20 = 91, "R_LARCH_TLS_IE_HI20";
+        TlsIeLo12 = 92, "R_LARCH_TLS_IE_LO12";
+        TlsIe64Lo20 = 93, "R_LARCH_TLS_IE64_LO20";
+        TlsIe64Hi12 = 94, "R_LARCH_TLS_IE64_HI12";
+        TlsLdPcHi20 = 95, "R_LARCH_TLS_LD_PC_HI20";
+        TlsLdHi20 = 96, "R_LARCH_TLS_LD_HI20";
+        TlsGdPcHi20 = 97, "R_LARCH_TLS_GD_PC_HI20";
+        TlsGdHi20 = 98, "R_LARCH_TLS_GD_HI20";
+        Data32Pcrel = 99, "R_LARCH_32_PCREL";
+        Relax = 100, "R_LARCH_RELAX";
+        Delete = 101, "R_LARCH_DELETE";
+        Align = 102, "R_LARCH_ALIGN";
+        Pcrel20S2 = 103, "R_LARCH_PCREL20_S2";
+        Cfa = 104, "R_LARCH_CFA";
+        Add6 = 105, "R_LARCH_ADD6";
+        Sub6 = 106, "R_LARCH_SUB6";
+        AddUleb128 = 107, "R_LARCH_ADD_ULEB128";
+        SubUleb128 = 108, "R_LARCH_SUB_ULEB128";
+        Data64Pcrel = 109, "R_LARCH_64_PCREL";
+        Call36 = 110, "R_LARCH_CALL36";
+        TlsDescPcHi20 = 111, "R_LARCH_TLS_DESC_PC_HI20";
+        TlsDescPcLo12 = 112, "R_LARCH_TLS_DESC_PC_LO12";
+        TlsDesc64PcLo20 = 113, "R_LARCH_TLS_DESC64_PC_LO20";
+        TlsDesc64PcHi12 = 114, "R_LARCH_TLS_DESC64_PC_HI12";
+        TlsDescHi20 = 115, "R_LARCH_TLS_DESC_HI20";
+        TlsDescLo12 = 116, "R_LARCH_TLS_DESC_LO12";
+        TlsDesc64Lo20 = 117, "R_LARCH_TLS_DESC64_LO20";
+        TlsDesc64Hi12 = 118, "R_LARCH_TLS_DESC64_HI12";
+        TlsDescLd = 119, "R_LARCH_TLS_DESC_LD";
+        TlsDescCall = 120, "R_LARCH_TLS_DESC_CALL";
+        TlsLeHi20R = 121, "R_LARCH_TLS_LE_HI20_R";
+        TlsLeAddR = 122, "R_LARCH_TLS_LE_ADD_R";
+        TlsLeLo12R = 123, "R_LARCH_TLS_LE_LO12_R";
+        TlsLdPcrel20S2 = 124, "R_LARCH_TLS_LD_PCREL20_S2";
+        TlsGdPcrel20S2 = 125, "R_LARCH_TLS_GD_PCREL20_S2";
+        TlsDescPcrel20S2 = 126, "R_LARCH_TLS_DESC_PCREL20_S2";
+    }
+}
@@ -1,8 +1,12 @@
+#[cfg(feature = "alloc")]
+use crate::compression::{Compression, DecompressError, GnuCompression};
 use crate::context::PropU32;
 use crate::context::*;
 use crate::elf::Variant;
 use crate::strtab::{ParseStrtabError, Strtab};
 use crate::utils::{as_offset, read, read_n, Pod};
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
 use core::marker::PhantomData;
 use core::ops::RangeInclusive;
 
@@ -75,10 +79,56 @@ impl<'a, T: Context> Sections<'a, T> {
     pub fn num(&self) -> u16 {
         self.num
     }
+    pub fn iter(&self) -> impl Iterator<Item = (u16, Result<Section<'a, T>, ParseSectionError>)> {
+        let sections = *self;
+        (0..self.num).map(move |i| (i, Section::parse(sections, i).unwrap()))
+    }
+    /// Finds sections whose names and sections satisfy the predicate.
+    ///
+    /// Names are resolved through the section header string table.
+    /// Sections which fail to parse or whose names cannot be resolved are skipped.
+    pub fn find_all<F>(&self, mut predicate: F) -> impl Iterator<Item = Section<'a, T>>
+    where
+        F: FnMut(&'a [u8], &Section<'a, T>) -> bool,
+    {
+        let shstrtab = Shstrtab::parse(*self).ok().flatten();
+        self.iter().filter_map(move |(_, section)| {
+            let section = section.ok()?;
+            let name = shstrtab?.strtab().find(section.header().name() as usize)?;
+            predicate(name, &section).then_some(section)
+        })
+    }
+    /// Finds the first section with the name.
+    pub fn by_name(&self, name: &[u8]) -> Option<Section<'a, T>> {
+        self.find_all(|x, _| x == name).next()
+    }
+    /// Finds the first section with the name, and returns its decompressed content.
+    ///
+    /// If the name starts with ".debug" and no section is found, the section compressed in the GNU style, which starts with ".zdebug", is tried.
+    #[cfg(feature = "alloc")]
+    pub fn decompressed_by_name(
+        &self,
+        name: &[u8],
+    ) -> Option<Result<Cow<'a, [u8]>, DecompressError>> {
+        if let Some(section) = self.by_name(name) {
+            return Some(section.decompressed());
+        }
+        let suffix = name.strip_prefix(b".debug")?;
+        let section = self
+            .find_all(|x, _| x.strip_prefix(b".zdebug") == Some(suffix))
+            .next()?;
+        Some(
+            GnuCompression::parse(section.content())
+                .map_err(DecompressError::FromCompression)
+                .and_then(|x| x.decompress())
+                .map(Cow::Owned),
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct Section<'a, T: Context> {
+    index: u16,
     sheader: &'a SectionHeader<T>,
     content: &'a [u8],
 }
@@ -97,30 +147,43 @@ impl<'a, T: Context> Section<'a, T> {
         fn helper<'a, T: Context>(
             data: &'a [u8],
             offset: usize,
+            index: u16,
         ) -> Result<Section<'a, T>, ParseSectionError> {
             let sheader: &'a SectionHeader<T> = read(data, offset).unwrap();
             let typa =
                 SectionType::try_from(T::interpret(sheader.typa)).map_err(|_| BadPropertyType)?;
             match typa {
                 Null => Ok(Section {
+                    index,
                     sheader,
                     content: &[],
                 }),
                 Nobits => {
                     let content_offset = as_offset::<T>(sheader.offset()).ok_or(BrokenContent)?;
                     let content = read_n::<u8>(data, content_offset, 0).ok_or(BrokenContent)?;
-                    Ok(Section { sheader, content })
+                    Ok(Section {
+                        index,
+                        sheader,
+                        content,
+                    })
                 }
                 _ => {
                     let content_offset = as_offset::<T>(sheader.offset()).ok_or(BrokenContent)?;
                     let content_size = as_offset::<T>(sheader.size()).ok_or(BrokenContent)?;
                     let content =
                         read_n::<u8>(data, content_offset, content_size).ok_or(BrokenContent)?;
-                    Ok(Section { sheader, content })
+                    Ok(Section {
+                        index,
+                        sheader,
+                        content,
+                    })
                 }
             }
         }
-        Some(helper(sections.data, offset))
+        Some(helper(sections.data, offset, index))
+    }
+    pub fn index(&self) -> u16 {
+        self.index
     }
     pub fn header(&self) -> &'a SectionHeader<T> {
         self.sheader
@@ -128,6 +191,18 @@ impl<'a, T: Context> Section<'a, T> {
     pub fn content(&self) -> &'a [u8] {
         self.content
     }
+    /// Returns the content, which is decompressed if the section is compressed.
+    #[cfg(feature = "alloc")]
+    pub fn decompressed(&self) -> Result<Cow<'a, [u8]>, DecompressError> {
+        use DecompressError::*;
+        let flags: T::Integer = self.sheader.flags().into();
+        let flags: u64 = flags.into();
+        if flags & u64::from(SectionFlags32::COMPRESSED.0) == 0 {
+            return Ok(Cow::Borrowed(self.content));
+        }
+        let compression = Compression::<T>::parse(self.content).map_err(FromCompression)?;
+        Ok(Cow::Owned(compression.decompress()?))
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -332,6 +407,15 @@ impl From<SectionType> for u32 {
     }
 }
 
+/// GNU-style hash table.
+pub const SECTION_TYPE_GNU_HASH: SectionType = SectionType::OsSpecific(0x6ffffff6);
+/// GNU version definition section.
+pub const SECTION_TYPE_GNU_VERDEF: SectionType = SectionType::OsSpecific(0x6ffffffd);
+/// GNU version requirement section.
+pub const SECTION_TYPE_GNU_VERNEED: SectionType = SectionType::OsSpecific(0x6ffffffe);
+/// GNU version symbol section.
+pub const SECTION_TYPE_GNU_VERSYM: SectionType = SectionType::OsSpecific(0x6fffffff);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, From, Into, BitXor, BitAnd, BitOr, LowerHex)]
 pub struct SectionFlags32(pub u32);
 
@@ -0,0 +1,178 @@
+use crate::context::*;
+use crate::dynamic_info::{DynamicView, ParseDynamicViewError};
+use crate::elf::{Variant, ELF_MACHINE_ARM};
+use crate::section::{ParseSectionsError, Section, SectionType, Sections};
+use crate::symbols::{ParseSymbolsError, Symbol, Symbols};
+use crate::symtab::{SymbolBinding, SymbolSection, SymbolType, SYMBOL_TYPE_GNU_IFUNC};
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone)]
+pub enum ParseSymbolizerError {
+    MissingSymbols,
+    FromSections(ParseSectionsError),
+    FromSymbols(ParseSymbolsError),
+    FromDynamicView(ParseDynamicViewError),
+}
+
+/// Function or object symbol, whose address is a link-time address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolizerEntry<'a> {
+    pub name: &'a [u8],
+    pub address: u64,
+    /// Size of the symbol, or the gap to the next symbol or the end of its section if the size is zero.
+    pub size: u64,
+}
+
+/// Symbolizer of addresses, which is an index of function and object symbols sorted by addresses.
+#[derive(Debug, Clone)]
+pub struct Symbolizer<'a> {
+    entries: Vec<SymbolizerEntry<'a>>,
+    /// Index of the nearest symbol before each symbol that contains it.
+    parents: Vec<Option<usize>>,
+    bias: u64,
+}
+
+impl<'a> Symbolizer<'a> {
+    /// Builds the index from ".symtab", or ".dynsym" if there is no ".symtab".
+    ///
+    /// Dynamic symbols are found by the dynamic program if there are no section headers.
+    pub fn parse<T: Context>(elf: Variant<'a, T>) -> Result<Self, ParseSymbolizerError> {
+        use ParseSymbolizerError::*;
+        let sections = Sections::parse(elf).map_err(FromSections)?;
+        let symbols = match sections {
+            Some(sections) => {
+                let section = sections
+                    .find_all(|_, x| x.header().checked_type() == Some(SectionType::Symtab))
+                    .next()
+                    .or_else(|| {
+                        sections
+                            .find_all(|_, x| x.header().checked_type() == Some(SectionType::Dynsym))
+                            .next()
+                    })
+                    .ok_or(MissingSymbols)?;
+                Symbols::parse(sections, section).map_err(FromSymbols)?
+            }
+            None => DynamicView::parse(elf)
+                .map_err(FromDynamicView)?
+                .and_then(|x| x.symbols())
+                .ok_or(MissingSymbols)?,
+        };
+        Ok(Self::new(elf, sections, symbols.iter()))
+    }
+    /// Builds the index from symbols, such as symbols merged from ".dynsym" and MiniDebugInfo.
+    ///
+    /// The ELF file gives the architecture, and its sections, if any, limit symbols without sizes.
+    pub fn new<T: Context>(
+        elf: Variant<'a, T>,
+        sections: Option<Sections<'a, T>>,
+        symbols: impl IntoIterator<Item = Symbol<'a, T>>,
+    ) -> Self {
+        let thumb = elf.header().machine() == ELF_MACHINE_ARM;
+        // the end of the section, which limits gaps
+        let end = |x: &Symbol<'a, T>| -> Option<u64> {
+            let index = match x.section() {
+                SymbolSection::Index(x) => u16::try_from(x).ok()?,
+                _ => return None,
+            };
+            let section = Section::parse(sections?, index)?.ok()?;
+            let addr: u64 = section.header().addr().into();
+            let size: u64 = section.header().size().into();
+            Some(addr.wrapping_add(size))
+        };
+        let mut entries = Vec::new();
+        for symbol in symbols {
+            let entry = symbol.entry();
+            let name = match symbol.name() {
+                Some(x) if !x.is_empty() => x,
+                _ => continue,
+            };
+            let func = match entry.checked_type() {
+                Some(SymbolType::Func) => true,
+                Some(x) if x == SYMBOL_TYPE_GNU_IFUNC => true,
+                Some(SymbolType::Object) => false,
+                _ => continue,
+            };
+            if !matches!(
+                symbol.section(),
+                SymbolSection::Index(_) | SymbolSection::Abs
+            ) {
+                continue;
+            }
+            let mut address: u64 = entry.value().into();
+            // the lowest bit of a function address is set in Thumb code
+            if thumb && func {
+                address &= !1;
+            }
+            let global = entry.checked_binding() == Some(SymbolBinding::Global);
+            entries.push((
+                SymbolizerEntry {
+                    name,
+                    address,
+                    size: entry.size().into(),
+                },
+                global,
+                end(&symbol),
+            ));
+        }
+        // symbols with sizes, then global symbols win if they share the same address
+        entries.sort_by_key(|(x, global, _)| (x.address, x.size == 0, !global));
+        entries.dedup_by_key(|(x, _, _)| x.address);
+        for i in 0..entries.len() {
+            if entries[i].0.size != 0 {
+                continue;
+            }
+            let next = entries.get(i + 1).map(|x| x.0.address);
+            let limit = match (next, entries[i].2) {
+                (Some(x), Some(y)) => core::cmp::min(x, y),
+                (x, y) => x.or(y).unwrap_or(entries[i].0.address),
+            };
+            entries[i].0.size = limit.saturating_sub(entries[i].0.address);
+        }
+        let entries = entries.into_iter().map(|(x, _, _)| x).collect::<Vec<_>>();
+        // symbols nested in a symbol, such as local labels in a function, leave the rest of it to the outer symbol
+        let mut parents = Vec::with_capacity(entries.len());
+        let mut stack = Vec::<usize>::new();
+        for (i, entry) in entries.iter().enumerate() {
+            while let Some(&top) = stack.last() {
+                if contains(&entries[top], entry.address) {
+                    break;
+                }
+                stack.pop();
+            }
+            parents.push(stack.last().copied());
+            stack.push(i);
+        }
+        Self {
+            entries,
+            parents,
+            bias: 0,
+        }
+    }
+    pub fn entries(&self) -> &[SymbolizerEntry<'a>] {
+        &self.entries
+    }
+    /// Difference between runtime addresses and link-time addresses, which is the load address of a position-independent file.
+    pub fn bias(&self) -> u64 {
+        self.bias
+    }
+    pub fn set_bias(&mut self, bias: u64) {
+        self.bias = bias;
+    }
+    /// Returns the innermost symbol containing the runtime address, and the offset of the address in the symbol.
+    pub fn lookup(&self, address: u64) -> Option<(&'a [u8], u64)> {
+        let address = address.wrapping_sub(self.bias);
+        let index = self.entries.partition_point(|x| x.address <= address);
+        let mut index = index.checked_sub(1)?;
+        while !contains(&self.entries[index], address) {
+            index = self.parents[index]?;
+        }
+        let entry = &self.entries[index];
+        Some((entry.name, address - entry.address))
+    }
+}
+
+/// Returns "true" if the address is in the symbol, or it's the address of a symbol without size.
+fn contains(entry: &SymbolizerEntry<'_>, address: u64) -> bool {
+    let offset = address - entry.address;
+    offset < entry.size || offset == 0
+}
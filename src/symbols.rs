@@ -0,0 +1,173 @@
+use crate::context::*;
+use crate::section::{ParseSectionError, Section, SectionType, Sections};
+use crate::shndx::{ParseShndxError, Shndx};
+use crate::strtab::{ParseStrtabError, Strtab};
+use crate::symtab::{ParseSymtabError, SymbolSection, Symtab, SymtabEntry};
+
+#[derive(Debug, Clone)]
+pub enum ParseSymbolsError {
+    BadPropertyType,
+    FromSymtab(ParseSymtabError),
+    BadPropertyLink,
+    FromSection(ParseSectionError),
+    BadPropertyLinkType,
+    FromStrtab(ParseStrtabError),
+    FromShndx(ParseShndxError),
+    BrokenShndx,
+    MissingShndx,
+}
+
+/// Symbols of a symtab section, joined with its string table and its extended section indexes.
+#[derive(Debug, Clone, Copy)]
+pub struct Symbols<'a, T: Context> {
+    symtab: Symtab<'a, T>,
+    strtab: Strtab<'a>,
+    shndx: Option<Shndx<'a, T>>,
+    info: u32,
+}
+
+impl<'a, T: Context> Symbols<'a, T> {
+    /// "info" is one greater than the index of the last local symbol.
+    ///
+    /// The extended section indexes are checked to cover every symbol whose "st_shndx" is "SHN_XINDEX".
+    pub fn new(
+        symtab: Symtab<'a, T>,
+        strtab: Strtab<'a>,
+        shndx: Option<Shndx<'a, T>>,
+        info: u32,
+    ) -> Result<Self, ParseSymbolsError> {
+        use ParseSymbolsError::*;
+        match shndx {
+            Some(x) if x.entries().len() < symtab.entries().len() => return Err(BrokenShndx),
+            Some(_) => (),
+            None => {
+                if symtab
+                    .iter()
+                    .any(|(_, x)| x.section() == SymbolSection::XIndex)
+                {
+                    return Err(MissingShndx);
+                }
+            }
+        }
+        Ok(Self {
+            symtab,
+            strtab,
+            shndx,
+            info,
+        })
+    }
+    /// Parses a symtab or dynsym section.
+    ///
+    /// The string table is given by "sh_link" of the section.
+    /// The extended section indexes are given by the symtab shndx section whose "sh_link" is the section.
+    pub fn parse(
+        sections: Sections<'a, T>,
+        section: Section<'a, T>,
+    ) -> Result<Self, ParseSymbolsError> {
+        use ParseSymbolsError::*;
+        match section.header().checked_type() {
+            Some(SectionType::Symtab | SectionType::Dynsym) => (),
+            _ => return Err(BadPropertyType),
+        }
+        let symtab = Symtab::parse(section.content()).map_err(FromSymtab)?;
+        let link = u16::try_from(section.header().link()).map_err(|_| BadPropertyLink)?;
+        let strtab = Section::parse(sections, link)
+            .ok_or(BadPropertyLink)?
+            .map_err(FromSection)?;
+        if strtab.header().checked_type() != Some(SectionType::Strtab) {
+            return Err(BadPropertyLinkType);
+        }
+        let strtab = Strtab::parse(strtab.content()).map_err(FromStrtab)?;
+        let mut shndx = None;
+        for (_, x) in sections.iter() {
+            let x = match x {
+                Ok(x) => x,
+                Err(_) => continue,
+            };
+            if x.header().checked_type() == Some(SectionType::SymtabShndx)
+                && x.header().link() == section.index() as u32
+            {
+                shndx = Some(Shndx::parse(x.content()).map_err(FromShndx)?);
+                break;
+            }
+        }
+        Self::new(symtab, strtab, shndx, section.header().info())
+    }
+    pub fn symtab(&self) -> Symtab<'a, T> {
+        self.symtab
+    }
+    pub fn strtab(&self) -> Strtab<'a> {
+        self.strtab
+    }
+    pub fn shndx(&self) -> Option<Shndx<'a, T>> {
+        self.shndx
+    }
+    /// The number of symbols, including the null symbol.
+    pub fn num(&self) -> usize {
+        self.symtab.entries().len()
+    }
+    pub fn get(&self, index: usize) -> Option<Symbol<'a, T>> {
+        let entry = self.symtab.entries().get(index)?;
+        Some(self.resolve(index, entry))
+    }
+    /// Iterates over symbols, skipping the null symbol.
+    pub fn iter(&self) -> impl Iterator<Item = Symbol<'a, T>> {
+        let symbols = *self;
+        self.symtab
+            .iter()
+            .map(move |(index, entry)| symbols.resolve(index, entry))
+    }
+    /// Iterates over local symbols, skipping the null symbol.
+    pub fn locals(&self) -> impl Iterator<Item = Symbol<'a, T>> {
+        let symbols = *self;
+        self.symtab
+            .locals(self.info)
+            .map(move |(index, entry)| symbols.resolve(index, entry))
+    }
+    /// Iterates over non-local symbols.
+    pub fn globals(&self) -> impl Iterator<Item = Symbol<'a, T>> {
+        let symbols = *self;
+        self.symtab
+            .globals(self.info)
+            .map(move |(index, entry)| symbols.resolve(index, entry))
+    }
+    fn resolve(&self, index: usize, entry: &'a SymtabEntry<T>) -> Symbol<'a, T> {
+        let name = self.strtab.find(entry.name() as usize);
+        let section = match (entry.section(), self.shndx) {
+            (SymbolSection::XIndex, Some(x)) => SymbolSection::Index(x.entries()[index].value()),
+            (x, _) => x,
+        };
+        Symbol {
+            index,
+            entry,
+            name,
+            section,
+        }
+    }
+}
+
+/// Resolved symbol.
+#[derive(Debug, Clone, Copy)]
+pub struct Symbol<'a, T: Context> {
+    index: usize,
+    entry: &'a SymtabEntry<T>,
+    name: Option<&'a [u8]>,
+    section: SymbolSection,
+}
+
+impl<'a, T: Context> Symbol<'a, T> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    pub fn entry(&self) -> &'a SymtabEntry<T> {
+        self.entry
+    }
+    /// Returns "None" if the name is out of the string table.
+    pub fn name(&self) -> Option<&'a [u8]> {
+        self.name
+    }
+    /// Returns the extended section index if "st_shndx" is "SHN_XINDEX".
+    pub fn section(&self) -> SymbolSection {
+        self.section
+    }
+}
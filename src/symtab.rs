@@ -1,5 +1,8 @@
 use crate::context::*;
 use crate::context::{PropU16, PropU32};
+use crate::section::{SECTION_INDEX_ABS, SECTION_INDEX_COMMON, SECTION_INDEX_UNDEF};
+use crate::section::{SECTION_INDEX_OSSPECIFIC, SECTION_INDEX_PROCESSORSPECIFIC};
+use crate::section::{SECTION_INDEX_RESERVE, SECTION_INDEX_XINDEX};
 use crate::utils::{read_s, Pod};
 
 #[derive(Debug, Clone)]
@@ -22,6 +25,24 @@ impl<'a, T: Context> Symtab<'a, T> {
     pub fn entries(&self) -> &'a [SymtabEntry<T>] {
         self.entries
     }
+    /// Iterates over symbols with their indexes, skipping the null symbol.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &'a SymtabEntry<T>)> {
+        self.entries.iter().enumerate().skip(1)
+    }
+    /// Iterates over local symbols, skipping the null symbol.
+    ///
+    /// "info" is "sh_info" of the section, which is one greater than the index of the last local symbol.
+    pub fn locals(&self, info: u32) -> impl Iterator<Item = (usize, &'a SymtabEntry<T>)> {
+        let end = core::cmp::min(info as usize, self.entries.len());
+        self.entries[..end].iter().enumerate().skip(1)
+    }
+    /// Iterates over non-local symbols.
+    ///
+    /// "info" is "sh_info" of the section, which is one greater than the index of the last local symbol.
+    pub fn globals(&self, info: u32) -> impl Iterator<Item = (usize, &'a SymtabEntry<T>)> {
+        let start = core::cmp::max(info as usize, 1);
+        self.entries.iter().enumerate().skip(start)
+    }
 }
 
 #[repr(C)]
@@ -56,6 +77,214 @@ impl<T: Context> SymtabEntry<T> {
     pub fn shndx(&self) -> u16 {
         T::interpret(self.shndx)
     }
+    pub fn checked_binding(&self) -> Option<SymbolBinding> {
+        SymbolBinding::try_from(self.info >> 4).ok()
+    }
+    /// # Panics
+    ///
+    /// Panics if the value is invaild.
+    pub fn binding(&self) -> SymbolBinding {
+        self.checked_binding().unwrap()
+    }
+    pub fn checked_type(&self) -> Option<SymbolType> {
+        SymbolType::try_from(self.info & 0xf).ok()
+    }
+    /// # Panics
+    ///
+    /// Panics if the value is invaild.
+    pub fn typa(&self) -> SymbolType {
+        self.checked_type().unwrap()
+    }
+    pub fn visibility(&self) -> SymbolVisibility {
+        SymbolVisibility::try_from(self.other & 0x3).unwrap()
+    }
+    pub fn section(&self) -> SymbolSection {
+        SymbolSection::from(self.shndx())
+    }
 }
 
 unsafe impl<T: Context> Pod for SymtabEntry<T> {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolBinding {
+    /// Local symbol.
+    Local,
+    /// Global symbol.
+    Global,
+    /// Weak symbol.
+    Weak,
+    /// Operating system-specific.
+    OsSpecific(u8),
+    /// Processor-specific.
+    ProcessorSpecific(u8),
+}
+
+impl TryFrom<u8> for SymbolBinding {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use SymbolBinding::*;
+        match value {
+            0 => Ok(Local),
+            1 => Ok(Global),
+            2 => Ok(Weak),
+            x @ 10..=12 => Ok(OsSpecific(x)),
+            x @ 13..=15 => Ok(ProcessorSpecific(x)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<SymbolBinding> for u8 {
+    fn from(value: SymbolBinding) -> Self {
+        use SymbolBinding::*;
+        match value {
+            Local => 0,
+            Global => 1,
+            Weak => 2,
+            OsSpecific(x) => x,
+            ProcessorSpecific(x) => x,
+        }
+    }
+}
+
+/// Unique symbol, a GNU extension.
+pub const SYMBOL_BINDING_GNU_UNIQUE: SymbolBinding = SymbolBinding::OsSpecific(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolType {
+    /// Symbol type is unspecified.
+    NoType,
+    /// Symbol is a data object.
+    Object,
+    /// Symbol is a code object.
+    Func,
+    /// Symbol associated with a section.
+    Section,
+    /// Symbol's name is file name.
+    File,
+    /// Symbol is a common data object.
+    Common,
+    /// Symbol is thread-local data object.
+    Tls,
+    /// Operating system-specific.
+    OsSpecific(u8),
+    /// Processor-specific.
+    ProcessorSpecific(u8),
+}
+
+impl TryFrom<u8> for SymbolType {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use SymbolType::*;
+        match value {
+            0 => Ok(NoType),
+            1 => Ok(Object),
+            2 => Ok(Func),
+            3 => Ok(Section),
+            4 => Ok(File),
+            5 => Ok(Common),
+            6 => Ok(Tls),
+            x @ 10..=12 => Ok(OsSpecific(x)),
+            x @ 13..=15 => Ok(ProcessorSpecific(x)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<SymbolType> for u8 {
+    fn from(value: SymbolType) -> Self {
+        use SymbolType::*;
+        match value {
+            NoType => 0,
+            Object => 1,
+            Func => 2,
+            Section => 3,
+            File => 4,
+            Common => 5,
+            Tls => 6,
+            OsSpecific(x) => x,
+            ProcessorSpecific(x) => x,
+        }
+    }
+}
+
+/// Indirect code object, a GNU extension.
+pub const SYMBOL_TYPE_GNU_IFUNC: SymbolType = SymbolType::OsSpecific(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolVisibility {
+    /// Default symbol visibility rules.
+    Default,
+    /// Processor specific hidden class.
+    Internal,
+    /// Symbol unavailable in other modules.
+    Hidden,
+    /// Not preemptible, not exported.
+    Protected,
+}
+
+impl TryFrom<u8> for SymbolVisibility {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use SymbolVisibility::*;
+        match value {
+            0 => Ok(Default),
+            1 => Ok(Internal),
+            2 => Ok(Hidden),
+            3 => Ok(Protected),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<SymbolVisibility> for u8 {
+    fn from(value: SymbolVisibility) -> Self {
+        use SymbolVisibility::*;
+        match value {
+            Default => 0,
+            Internal => 1,
+            Hidden => 2,
+            Protected => 3,
+        }
+    }
+}
+
+/// The section which a symbol is defined relative to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolSection {
+    /// Undefined symbol.
+    Undef,
+    /// Absolute symbol, which is not affected by relocation.
+    Abs,
+    /// Common symbol, which is not yet allocated.
+    Common,
+    /// The actual section index is too large to fit in "st_shndx" and is to be found in the "SHT_SYMTAB_SHNDX" section.
+    XIndex,
+    /// Processor-specific.
+    ProcessorSpecific(u16),
+    /// Operating system-specific.
+    OsSpecific(u16),
+    /// Reserved.
+    Reserved(u16),
+    /// Section index.
+    Index(u32),
+}
+
+impl From<u16> for SymbolSection {
+    fn from(value: u16) -> Self {
+        use SymbolSection::*;
+        match value {
+            SECTION_INDEX_UNDEF => Undef,
+            SECTION_INDEX_ABS => Abs,
+            SECTION_INDEX_COMMON => Common,
+            SECTION_INDEX_XINDEX => XIndex,
+            x if SECTION_INDEX_PROCESSORSPECIFIC.contains(&x) => ProcessorSpecific(x),
+            x if SECTION_INDEX_OSSPECIFIC.contains(&x) => OsSpecific(x),
+            x if SECTION_INDEX_RESERVE.contains(&x) => Reserved(x),
+            x => Index(x as u32),
+        }
+    }
+}
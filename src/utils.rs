@@ -1,4 +1,7 @@
 use crate::context::Context;
+use crate::elf::ELF_MACHINE_MIPS;
+use crate::rel::Mips64Info;
+use crate::Class;
 use core::fmt::Debug;
 
 pub trait SealedContext {
@@ -18,6 +21,32 @@ pub trait Interpret<T> {
     fn interpret(x: T) -> Self::Target;
 }
 
+pub fn as_signed<T: Context>(x: T::Integer) -> i64 {
+    let y: u64 = x.into();
+    match T::CLASS {
+        Class::Class32 => y as u32 as i32 as i64,
+        Class::Class64 => y as i64,
+    }
+}
+
+pub fn relocation_sym<T: Context>(machine: u16, info: T::Integer) -> u32 {
+    let y: u64 = info.into();
+    match T::CLASS {
+        Class::Class32 => (y >> 8) as u32,
+        Class::Class64 if machine == ELF_MACHINE_MIPS => Mips64Info::parse::<T>(info).sym,
+        Class::Class64 => (y >> 32) as u32,
+    }
+}
+
+pub fn relocation_type<T: Context>(machine: u16, info: T::Integer) -> u32 {
+    let y: u64 = info.into();
+    match T::CLASS {
+        Class::Class32 => (y & 0xff) as u32,
+        Class::Class64 if machine == ELF_MACHINE_MIPS => Mips64Info::parse::<T>(info).typa as u32,
+        Class::Class64 => (y & 0xffffffff) as u32,
+    }
+}
+
 pub fn as_offset<T: Context>(x: T::Integer) -> Option<usize> {
     let y: u64 = x.into();
     y.try_into().ok()
@@ -0,0 +1,558 @@
+use crate::context::*;
+use crate::context::{PropU16, PropU32};
+use crate::section::{ParseSectionError, Section, SectionType, Sections};
+use crate::section::{SECTION_TYPE_GNU_VERDEF, SECTION_TYPE_GNU_VERNEED, SECTION_TYPE_GNU_VERSYM};
+use crate::strtab::{ParseStrtabError, Strtab};
+use crate::utils::{read, read_s, Pod};
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub enum ParseVersymError {
+    BrokenEntry,
+}
+
+#[derive(Debug, Clone)]
+pub enum ParseVerdefError {
+    BrokenEntry,
+    BrokenAux,
+}
+
+#[derive(Debug, Clone)]
+pub enum ParseVerneedError {
+    BrokenEntry,
+    BrokenAux,
+}
+
+#[derive(Debug, Clone)]
+pub enum ParseVersionsError {
+    FromSection(ParseSectionError),
+    BadPropertyLink,
+    BadPropertyLinkType,
+    FromStrtab(ParseStrtabError),
+    FromVersym(ParseVersymError),
+    FromVerdef(ParseVerdefError),
+    FromVerneed(ParseVerneedError),
+}
+
+/// The symbol is local.
+pub const VERSYM_LOCAL: u16 = 0;
+/// The symbol is global and unversioned.
+pub const VERSYM_GLOBAL: u16 = 1;
+/// The symbol is hidden, it's not the default version.
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+
+/// The version definition of the file itself.
+pub const VERDEF_FLAG_BASE: u16 = 0x1;
+/// Weak version identifier.
+pub const VERDEF_FLAG_WEAK: u16 = 0x2;
+
+/// GNU version symbol section.
+#[derive(Debug, Clone, Copy)]
+pub struct Versym<'a, T: Context> {
+    entries: &'a [VersymEntry<T>],
+}
+
+impl<'a, T: Context> Versym<'a, T> {
+    pub fn parse(content: &'a [u8]) -> Result<Self, ParseVersymError> {
+        use ParseVersymError::*;
+        let entries = read_s(content).ok_or(BrokenEntry)?;
+        Ok(Self { entries })
+    }
+    pub fn entries(&self) -> &'a [VersymEntry<T>] {
+        self.entries
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct VersymEntry<T: Context> {
+    pub value: PropU16,
+    pub _maker: PhantomData<T>,
+}
+
+impl<T: Context> VersymEntry<T> {
+    pub fn value(&self) -> u16 {
+        T::interpret(self.value)
+    }
+    /// Version index, without the hidden bit.
+    pub fn index(&self) -> u16 {
+        self.value() & !VERSYM_HIDDEN
+    }
+    pub fn hidden(&self) -> bool {
+        self.value() & VERSYM_HIDDEN != 0
+    }
+}
+
+unsafe impl<T: Context> Pod for VersymEntry<T> {}
+
+/// GNU version definition section.
+#[derive(Debug, Clone, Copy)]
+pub struct Verdef<'a, T: Context> {
+    content: &'a [u8],
+    num: usize,
+    _maker: PhantomData<T>,
+}
+
+impl<'a, T: Context> Verdef<'a, T> {
+    pub fn parse(content: &'a [u8]) -> Result<Self, ParseVerdefError> {
+        use ParseVerdefError::*;
+        let max = content.len() / core::mem::size_of::<VerdefEntry<T>>();
+        let mut num = 0usize;
+        let mut offset = 0usize;
+        while num < max {
+            let entry: &VerdefEntry<T> = read(content, offset).ok_or(BrokenEntry)?;
+            let mut aux_offset = offset.checked_add(entry.aux() as usize).ok_or(BrokenAux)?;
+            for _ in 0..entry.cnt() {
+                let aux: &VerdauxEntry<T> = read(content, aux_offset).ok_or(BrokenAux)?;
+                aux_offset = aux_offset.wrapping_add(aux.next() as usize);
+            }
+            num += 1;
+            if entry.next() == 0 {
+                return Ok(Self {
+                    content,
+                    num,
+                    _maker: PhantomData,
+                });
+            }
+            offset = offset
+                .checked_add(entry.next() as usize)
+                .ok_or(BrokenEntry)?;
+        }
+        if content.is_empty() {
+            return Ok(Self {
+                content,
+                num,
+                _maker: PhantomData,
+            });
+        }
+        // the chain does not end
+        Err(BrokenEntry)
+    }
+    pub fn num(&self) -> usize {
+        self.num
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Definition<'a, T>> {
+        let content = self.content;
+        let mut offset = 0usize;
+        (0..self.num).map(move |_| {
+            let entry: &'a VerdefEntry<T> = read(content, offset).unwrap();
+            let definition = Definition {
+                content,
+                offset,
+                entry,
+            };
+            offset = offset.wrapping_add(entry.next() as usize);
+            definition
+        })
+    }
+    /// Finds the version definition with the version index.
+    pub fn find(&self, index: u16) -> Option<Definition<'a, T>> {
+        self.iter().find(|x| x.entry().ndx() == index)
+    }
+}
+
+/// Version definition.
+#[derive(Debug, Clone, Copy)]
+pub struct Definition<'a, T: Context> {
+    content: &'a [u8],
+    offset: usize,
+    entry: &'a VerdefEntry<T>,
+}
+
+impl<'a, T: Context> Definition<'a, T> {
+    pub fn entry(&self) -> &'a VerdefEntry<T> {
+        self.entry
+    }
+    /// Names of the version and its parents.
+    pub fn auxs(&self) -> impl Iterator<Item = &'a VerdauxEntry<T>> {
+        let content = self.content;
+        let mut offset = self.offset.wrapping_add(self.entry.aux() as usize);
+        (0..self.entry.cnt()).map(move |_| {
+            let aux: &'a VerdauxEntry<T> = read(content, offset).unwrap();
+            offset = offset.wrapping_add(aux.next() as usize);
+            aux
+        })
+    }
+    /// The string table offset of the version name.
+    pub fn name(&self) -> Option<u32> {
+        self.auxs().next().map(|x| x.name())
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct VerdefEntry<T: Context> {
+    pub version: PropU16,
+    pub flags: PropU16,
+    pub ndx: PropU16,
+    pub cnt: PropU16,
+    pub hash: PropU32,
+    pub aux: PropU32,
+    pub next: PropU32,
+    pub _maker: PhantomData<T>,
+}
+
+impl<T: Context> VerdefEntry<T> {
+    pub fn version(&self) -> u16 {
+        T::interpret(self.version)
+    }
+    pub fn flags(&self) -> u16 {
+        T::interpret(self.flags)
+    }
+    pub fn ndx(&self) -> u16 {
+        T::interpret(self.ndx)
+    }
+    pub fn cnt(&self) -> u16 {
+        T::interpret(self.cnt)
+    }
+    pub fn hash(&self) -> u32 {
+        T::interpret(self.hash)
+    }
+    pub fn aux(&self) -> u32 {
+        T::interpret(self.aux)
+    }
+    pub fn next(&self) -> u32 {
+        T::interpret(self.next)
+    }
+}
+
+unsafe impl<T: Context> Pod for VerdefEntry<T> {}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct VerdauxEntry<T: Context> {
+    pub name: PropU32,
+    pub next: PropU32,
+    pub _maker: PhantomData<T>,
+}
+
+impl<T: Context> VerdauxEntry<T> {
+    pub fn name(&self) -> u32 {
+        T::interpret(self.name)
+    }
+    pub fn next(&self) -> u32 {
+        T::interpret(self.next)
+    }
+}
+
+unsafe impl<T: Context> Pod for VerdauxEntry<T> {}
+
+/// GNU version requirement section.
+#[derive(Debug, Clone, Copy)]
+pub struct Verneed<'a, T: Context> {
+    content: &'a [u8],
+    num: usize,
+    _maker: PhantomData<T>,
+}
+
+impl<'a, T: Context> Verneed<'a, T> {
+    pub fn parse(content: &'a [u8]) -> Result<Self, ParseVerneedError> {
+        use ParseVerneedError::*;
+        let max = content.len() / core::mem::size_of::<VerneedEntry<T>>();
+        let mut num = 0usize;
+        let mut offset = 0usize;
+        while num < max {
+            let entry: &VerneedEntry<T> = read(content, offset).ok_or(BrokenEntry)?;
+            let mut aux_offset = offset.checked_add(entry.aux() as usize).ok_or(BrokenAux)?;
+            for _ in 0..entry.cnt() {
+                let aux: &VernauxEntry<T> = read(content, aux_offset).ok_or(BrokenAux)?;
+                aux_offset = aux_offset.wrapping_add(aux.next() as usize);
+            }
+            num += 1;
+            if entry.next() == 0 {
+                return Ok(Self {
+                    content,
+                    num,
+                    _maker: PhantomData,
+                });
+            }
+            offset = offset
+                .checked_add(entry.next() as usize)
+                .ok_or(BrokenEntry)?;
+        }
+        if content.is_empty() {
+            return Ok(Self {
+                content,
+                num,
+                _maker: PhantomData,
+            });
+        }
+        // the chain does not end
+        Err(BrokenEntry)
+    }
+    pub fn num(&self) -> usize {
+        self.num
+    }
+    pub fn iter(&self) -> impl Iterator<Item = Requirement<'a, T>> {
+        let content = self.content;
+        let mut offset = 0usize;
+        (0..self.num).map(move |_| {
+            let entry: &'a VerneedEntry<T> = read(content, offset).unwrap();
+            let requirement = Requirement {
+                content,
+                offset,
+                entry,
+            };
+            offset = offset.wrapping_add(entry.next() as usize);
+            requirement
+        })
+    }
+    /// Finds the version requirement with the version index.
+    pub fn find(&self, index: u16) -> Option<(Requirement<'a, T>, &'a VernauxEntry<T>)> {
+        self.iter()
+            .find_map(|x| Some((x, x.auxs().find(|y| y.other() == index)?)))
+    }
+}
+
+/// Version requirements of a file.
+#[derive(Debug, Clone, Copy)]
+pub struct Requirement<'a, T: Context> {
+    content: &'a [u8],
+    offset: usize,
+    entry: &'a VerneedEntry<T>,
+}
+
+impl<'a, T: Context> Requirement<'a, T> {
+    pub fn entry(&self) -> &'a VerneedEntry<T> {
+        self.entry
+    }
+    /// Required versions.
+    pub fn auxs(&self) -> impl Iterator<Item = &'a VernauxEntry<T>> {
+        let content = self.content;
+        let mut offset = self.offset.wrapping_add(self.entry.aux() as usize);
+        (0..self.entry.cnt()).map(move |_| {
+            let aux: &'a VernauxEntry<T> = read(content, offset).unwrap();
+            offset = offset.wrapping_add(aux.next() as usize);
+            aux
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct VerneedEntry<T: Context> {
+    pub version: PropU16,
+    pub cnt: PropU16,
+    pub file: PropU32,
+    pub aux: PropU32,
+    pub next: PropU32,
+    pub _maker: PhantomData<T>,
+}
+
+impl<T: Context> VerneedEntry<T> {
+    pub fn version(&self) -> u16 {
+        T::interpret(self.version)
+    }
+    pub fn cnt(&self) -> u16 {
+        T::interpret(self.cnt)
+    }
+    pub fn file(&self) -> u32 {
+        T::interpret(self.file)
+    }
+    pub fn aux(&self) -> u32 {
+        T::interpret(self.aux)
+    }
+    pub fn next(&self) -> u32 {
+        T::interpret(self.next)
+    }
+}
+
+unsafe impl<T: Context> Pod for VerneedEntry<T> {}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct VernauxEntry<T: Context> {
+    pub hash: PropU32,
+    pub flags: PropU16,
+    pub other: PropU16,
+    pub name: PropU32,
+    pub next: PropU32,
+    pub _maker: PhantomData<T>,
+}
+
+impl<T: Context> VernauxEntry<T> {
+    pub fn hash(&self) -> u32 {
+        T::interpret(self.hash)
+    }
+    pub fn flags(&self) -> u16 {
+        T::interpret(self.flags)
+    }
+    /// Version index, which is referred by the version symbol section.
+    pub fn other(&self) -> u16 {
+        T::interpret(self.other)
+    }
+    pub fn name(&self) -> u32 {
+        T::interpret(self.name)
+    }
+    pub fn next(&self) -> u32 {
+        T::interpret(self.next)
+    }
+}
+
+unsafe impl<T: Context> Pod for VernauxEntry<T> {}
+
+/// Versions of dynamic symbols, joined from the version symbol, definition and requirement sections.
+#[derive(Debug, Clone, Copy)]
+pub struct Versions<'a, T: Context> {
+    versym: Versym<'a, T>,
+    verdef: Option<Verdef<'a, T>>,
+    verneed: Option<Verneed<'a, T>>,
+    strtab: Strtab<'a>,
+}
+
+impl<'a, T: Context> Versions<'a, T> {
+    /// "strtab" is the string table of the dynamic symbol table.
+    pub fn new(
+        versym: Versym<'a, T>,
+        verdef: Option<Verdef<'a, T>>,
+        verneed: Option<Verneed<'a, T>>,
+        strtab: Strtab<'a>,
+    ) -> Self {
+        Self {
+            versym,
+            verdef,
+            verneed,
+            strtab,
+        }
+    }
+    /// Returns "None" if there is no version symbol section.
+    pub fn parse(sections: Sections<'a, T>) -> Result<Option<Self>, ParseVersionsError> {
+        use ParseVersionsError::*;
+        let mut versym = None;
+        let mut verdef = None;
+        let mut verneed = None;
+        for (_, section) in sections.iter() {
+            let section = match section {
+                Ok(section) => section,
+                Err(_) => continue,
+            };
+            match section.header().typa() {
+                SECTION_TYPE_GNU_VERSYM => versym = Some(section),
+                SECTION_TYPE_GNU_VERDEF => verdef = Some(section),
+                SECTION_TYPE_GNU_VERNEED => verneed = Some(section),
+                _ => (),
+            }
+        }
+        let versym = match versym {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+        // the version symbol section links to the dynamic symbol table, which links to the string table
+        let link = |section: Section<'a, T>| -> Result<Section<'a, T>, ParseVersionsError> {
+            let link = u16::try_from(section.header().link()).map_err(|_| BadPropertyLink)?;
+            Section::parse(sections, link)
+                .ok_or(BadPropertyLink)?
+                .map_err(FromSection)
+        };
+        let dynsym = link(versym)?;
+        if dynsym.header().checked_type() != Some(SectionType::Dynsym) {
+            return Err(BadPropertyLinkType);
+        }
+        let strtab = link(dynsym)?;
+        if strtab.header().checked_type() != Some(SectionType::Strtab) {
+            return Err(BadPropertyLinkType);
+        }
+        Ok(Some(Self {
+            versym: Versym::parse(versym.content()).map_err(FromVersym)?,
+            verdef: verdef
+                .map(|x| Verdef::parse(x.content()))
+                .transpose()
+                .map_err(FromVerdef)?,
+            verneed: verneed
+                .map(|x| Verneed::parse(x.content()))
+                .transpose()
+                .map_err(FromVerneed)?,
+            strtab: Strtab::parse(strtab.content()).map_err(FromStrtab)?,
+        }))
+    }
+    pub fn versym(&self) -> Versym<'a, T> {
+        self.versym
+    }
+    pub fn verdef(&self) -> Option<Verdef<'a, T>> {
+        self.verdef
+    }
+    pub fn verneed(&self) -> Option<Verneed<'a, T>> {
+        self.verneed
+    }
+    pub fn strtab(&self) -> Strtab<'a> {
+        self.strtab
+    }
+    /// Gets the version of the dynamic symbol.
+    ///
+    /// Returns "None" if the symbol or its version is not found.
+    pub fn get(&self, index: usize) -> Option<SymbolVersion<'a>> {
+        let versym = self.versym.entries().get(index)?;
+        let hidden = versym.hidden();
+        match versym.index() {
+            VERSYM_LOCAL => return Some(SymbolVersion::Local),
+            VERSYM_GLOBAL => return Some(SymbolVersion::Global),
+            _ => (),
+        }
+        if let Some(definition) = self.verdef.and_then(|x| x.find(versym.index())) {
+            let name = self.strtab.find(definition.name()? as usize)?;
+            return Some(SymbolVersion::Defined { name, hidden });
+        }
+        if let Some((requirement, aux)) = self.verneed.and_then(|x| x.find(versym.index())) {
+            let name = self.strtab.find(aux.name() as usize)?;
+            let file = self.strtab.find(requirement.entry().file() as usize)?;
+            return Some(SymbolVersion::Needed { name, file, hidden });
+        }
+        None
+    }
+    /// Checks if the dynamic symbol has the version.
+    ///
+    /// If "version" is "None", checks if the dynamic symbol is the default version.
+    pub fn matches(&self, index: usize, version: Option<&[u8]>) -> bool {
+        match (self.get(index), version) {
+            (Some(x), None) => !x.hidden(),
+            (Some(x), Some(version)) => x.name() == Some(version),
+            (None, _) => false,
+        }
+    }
+}
+
+/// Version of a dynamic symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolVersion<'a> {
+    /// The symbol is local.
+    Local,
+    /// The symbol is global and unversioned.
+    Global,
+    /// The version is defined in this file.
+    Defined { name: &'a [u8], hidden: bool },
+    /// The version is required from the file.
+    Needed {
+        name: &'a [u8],
+        file: &'a [u8],
+        hidden: bool,
+    },
+}
+
+impl<'a> SymbolVersion<'a> {
+    /// The version name, e.g. "GLIBC_2.34".
+    pub fn name(&self) -> Option<&'a [u8]> {
+        use SymbolVersion::*;
+        match self {
+            Local | Global => None,
+            Defined { name, .. } | Needed { name, .. } => Some(name),
+        }
+    }
+    pub fn hidden(&self) -> bool {
+        use SymbolVersion::*;
+        match self {
+            Local | Global => false,
+            Defined { hidden, .. } | Needed { hidden, .. } => *hidden,
+        }
+    }
+    /// The separator between the symbol name and the version name.
+    ///
+    /// It's "@@" for the default version of a definition, "@" for others, and "" for unversioned symbols.
+    pub fn separator(&self) -> &'static str {
+        use SymbolVersion::*;
+        match self {
+            Local | Global => "",
+            Defined { hidden: false, .. } => "@@",
+            Defined { hidden: true, .. } | Needed { .. } => "@",
+        }
+    }
+}
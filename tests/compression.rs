@@ -0,0 +1,109 @@
+use zelf::compression::{Compression, CompressionType, DecompressError, GnuCompression};
+use zelf::context::Little64;
+use zelf::elf::Elf;
+use zelf::section::Sections;
+
+const SECTIONS: [&[u8]; 3] = [b".debug_info", b".debug_abbrev", b".debug_line"];
+
+#[test]
+fn decompressed() {
+    let bytes = std::fs::read("tests/data/debug-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let expected = Sections::parse(elf).unwrap().unwrap();
+    for (file, typa) in [
+        ("zlib-x86_64", CompressionType::Zlib),
+        ("zstd-x86_64", CompressionType::Zstd),
+    ] {
+        let bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+        let elf = match Elf::parse(&bytes).unwrap() {
+            Elf::Little64(elf) => elf,
+            _ => unreachable!(),
+        };
+        let sections = Sections::parse(elf).unwrap().unwrap();
+        for name in SECTIONS {
+            let section = sections.by_name(name).unwrap();
+            let compression = Compression::<Little64>::parse(section.content()).unwrap();
+            assert_eq!(compression.header().typa(), typa);
+            let content = expected.by_name(name).unwrap().content();
+            assert_eq!(&*section.decompressed().unwrap(), content);
+        }
+        // not compressed
+        let section = sections.by_name(b".debug_str").unwrap();
+        assert_eq!(&*section.decompressed().unwrap(), section.content());
+    }
+}
+
+#[test]
+fn decompressed_size() {
+    for file in ["zlib-x86_64", "zstd-x86_64"] {
+        let mut bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+        let offset = {
+            let elf = match Elf::parse(&bytes).unwrap() {
+                Elf::Little64(elf) => elf,
+                _ => unreachable!(),
+            };
+            let sections = Sections::parse(elf).unwrap().unwrap();
+            let section = sections.by_name(b".debug_info").unwrap();
+            section.content().as_ptr() as usize - bytes.as_ptr() as usize
+        };
+        // "ch_size" follows "ch_type" and "ch_reserved"
+        let size = u64::from_le_bytes(bytes[offset + 8..offset + 16].try_into().unwrap());
+        for size in [size - 1, size + 1, u64::MAX >> 1] {
+            bytes[offset + 8..offset + 16].copy_from_slice(&size.to_le_bytes());
+            let elf = match Elf::parse(&bytes).unwrap() {
+                Elf::Little64(elf) => elf,
+                _ => unreachable!(),
+            };
+            let sections = Sections::parse(elf).unwrap().unwrap();
+            let section = sections.by_name(b".debug_info").unwrap();
+            assert!(matches!(
+                section.decompressed(),
+                Err(DecompressError::BadPropertySize)
+            ));
+        }
+    }
+}
+
+#[test]
+fn decompressed_by_name() {
+    let bytes = std::fs::read("tests/data/debug-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let expected = Sections::parse(elf).unwrap().unwrap();
+    for file in [
+        "debug-x86_64",
+        "zlib-x86_64",
+        "zstd-x86_64",
+        "zlib-gnu-x86_64",
+    ] {
+        let bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+        let elf = match Elf::parse(&bytes).unwrap() {
+            Elf::Little64(elf) => elf,
+            _ => unreachable!(),
+        };
+        let sections = Sections::parse(elf).unwrap().unwrap();
+        for name in SECTIONS.iter().chain([&&b".debug_str"[..]]) {
+            let content = expected.by_name(name).unwrap().content();
+            let decompressed = sections.decompressed_by_name(name).unwrap().unwrap();
+            assert_eq!(&*decompressed, content);
+        }
+        assert!(sections.decompressed_by_name(b".debug_rnglists").is_none());
+    }
+    let bytes = std::fs::read("tests/data/zlib-gnu-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let section = sections.by_name(b".zdebug_info").unwrap();
+    let compression = GnuCompression::parse(section.content()).unwrap();
+    let size = expected.by_name(b".debug_info").unwrap().content().len();
+    assert_eq!(compression.size(), size as u64);
+    assert!(GnuCompression::parse(b"ZLIB").is_err());
+    assert!(GnuCompression::parse(b"ZSTD\0\0\0\0\0\0\0\0").is_err());
+}
@@ -0,0 +1,250 @@
+use zelf::context::{Big64, Context, Little32, Little64};
+use zelf::core_image::{CoreImage, Memory, Module, ReadCoreImageError};
+use zelf::core_note::*;
+use zelf::elf::{Elf, ElfType, Variant};
+use zelf::gnu_note::BuildId;
+use zelf::note::Notes;
+use zelf::program::{ProgramType, Programs};
+
+fn core_notes<T: Context>(elf: Variant<T>) -> Vec<CoreNote<T>> {
+    assert_eq!(elf.header().checked_type(), Some(ElfType::Core));
+    let programs = Programs::parse(elf).unwrap().unwrap();
+    let mut notes = Vec::new();
+    for (_, program) in programs.iter() {
+        let program = program.unwrap();
+        if program.header().typa() != ProgramType::Note {
+            continue;
+        }
+        let parsed = Notes::<T>::parse(program.content(), program.header().align()).unwrap();
+        notes.extend(parsed.iter().filter_map(|x| CoreNote::parse(x).unwrap()));
+    }
+    notes
+}
+
+#[test]
+fn core_x86_64() {
+    let bytes = std::fs::read("tests/data/core-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let notes = core_notes::<Little64>(elf);
+    let (mut prstatus, mut prpsinfo, mut siginfo, mut auxv, mut file) = (0, 0, 0, 0, 0);
+    for note in notes {
+        match note {
+            CoreNote::Prstatus(x) => {
+                prstatus += 1;
+                assert_eq!(x.header().cursig(), 11);
+                match x.registers(elf.header().machine()) {
+                    Registers::X86_64(x) => {
+                        assert_eq!(x.rax, 0x5a454c46);
+                        assert_eq!(x.rdi, 0x1234);
+                        assert!((0x401000..0x402000).contains(&x.rip));
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            CoreNote::Prpsinfo(x) => {
+                prpsinfo += 1;
+                assert_eq!(x.sname, b'R');
+                assert_eq!(x.fname, b"crash-x86_64");
+                assert_eq!(x.psargs, b"./crash-x86_64 zelf ");
+            }
+            CoreNote::Siginfo(x) => {
+                siginfo += 1;
+                assert_eq!(x.header().signo(), 11);
+                assert_eq!(x.addr(), Some(0x1234));
+                assert_eq!(x.sender(), None);
+            }
+            CoreNote::Auxv(x) => {
+                auxv += 1;
+                assert_eq!(x.find(AUXV_ENTRY), Some(0x401000));
+                assert_eq!(x.find(AUXV_PAGESZ), Some(4096));
+                assert_eq!(x.find(AUXV_BASE), Some(0));
+            }
+            CoreNote::File(x) => {
+                file += 1;
+                let files = x.iter().collect::<Vec<_>>();
+                assert!(!files.is_empty());
+                assert!(files.iter().all(|x| x.name().ends_with(b"/crash-x86_64")));
+                assert_eq!((files[0].start(), files[0].offset()), (0x400000, 0));
+            }
+            CoreNote::Unknown(..) => (),
+        }
+    }
+    assert_eq!((prstatus, prpsinfo, siginfo, auxv, file), (1, 1, 1, 1, 1));
+}
+
+#[test]
+fn core_i386() {
+    let bytes = std::fs::read("tests/data/core-i386").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little32(elf) => elf,
+        _ => unreachable!(),
+    };
+    let notes = core_notes::<Little32>(elf);
+    let mut prstatus = None;
+    let mut prpsinfo = None;
+    for note in notes {
+        match note {
+            CoreNote::Prstatus(x) => prstatus = Some(x),
+            CoreNote::Prpsinfo(x) => prpsinfo = Some(x),
+            CoreNote::Siginfo(x) => assert_eq!(x.addr(), Some(0x1234)),
+            CoreNote::Auxv(x) => assert_eq!(x.find(AUXV_ENTRY), Some(0x8049000)),
+            CoreNote::File(x) => {
+                let files = x.iter().map(|x| x.offset()).collect::<Vec<_>>();
+                assert_eq!(files, [0, 4096, 8192]);
+            }
+            CoreNote::Unknown(..) => (),
+        }
+    }
+    let prstatus = prstatus.unwrap();
+    let registers = prstatus.registers(elf.header().machine());
+    match registers {
+        Registers::I386(x) => {
+            assert_eq!(x.eax, 0x5a454c46);
+            assert_eq!(x.edi, 0x1234);
+        }
+        _ => unreachable!(),
+    }
+    assert!((0x8049000..0x804a000).contains(&registers.pc().unwrap()));
+    let prpsinfo = prpsinfo.unwrap();
+    assert_eq!(prpsinfo.pid, prstatus.header().pid());
+    assert_eq!(prpsinfo.fname, b"crash-i386");
+    assert_eq!(prpsinfo.psargs, b"./crash-i386 zelf ");
+}
+
+/// Returns the only "NT_PRSTATUS" and "NT_PRPSINFO" of a synthesized core dump, checking fields they share.
+fn synthesized<'a, T: Context>(
+    elf: Variant<'a, T>,
+    fname: &[u8],
+) -> (Prstatus<'a, T>, Prpsinfo<'a>) {
+    let mut prstatus = Vec::new();
+    let mut prpsinfo = Vec::new();
+    for note in core_notes(elf) {
+        match note {
+            CoreNote::Prstatus(x) => prstatus.push(x),
+            CoreNote::Prpsinfo(x) => prpsinfo.push(x),
+            _ => unreachable!(),
+        }
+    }
+    let ([prstatus], [prpsinfo]) = (&prstatus[..], &prpsinfo[..]) else {
+        unreachable!()
+    };
+    assert_eq!(prstatus.header().signo(), 11);
+    assert_eq!(prstatus.header().cursig(), 11);
+    let header = prstatus.header();
+    assert_eq!(
+        (header.pid(), header.ppid(), header.pgrp(), header.sid()),
+        (4242, 4241, 4242, 4241)
+    );
+    assert_eq!(
+        (prpsinfo.pid, prpsinfo.ppid, prpsinfo.pgrp, prpsinfo.sid),
+        (4242, 4241, 4242, 4241)
+    );
+    assert_eq!((prpsinfo.uid, prpsinfo.gid), (1000, 1000));
+    assert_eq!((prpsinfo.sname, prpsinfo.flag), (b'R', 0x400600));
+    assert_eq!(prpsinfo.fname, fname);
+    assert_eq!(&prpsinfo.psargs[..2], b"./");
+    assert_eq!(&prpsinfo.psargs[2..2 + fname.len()], fname);
+    (*prstatus, *prpsinfo)
+}
+
+#[test]
+fn core_aarch64() {
+    let bytes = std::fs::read("tests/data/core-aarch64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let (prstatus, _) = synthesized(elf, b"crash-aarch64");
+    // "fpvalid" and padding follow registers
+    assert_eq!(prstatus.raw_registers().len(), 34 * 8 + 8);
+    let registers = prstatus.registers(elf.header().machine());
+    match registers {
+        Registers::Aarch64(x) => {
+            assert_eq!((x.x0, x.x1), (0x5a454c46, 0x1234));
+            assert_eq!(x.pstate, 0x60000000);
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(registers.pc(), Some(0x400120));
+    assert_eq!(registers.sp(), Some(0xfffffffff000));
+}
+
+#[test]
+fn core_riscv64() {
+    let bytes = std::fs::read("tests/data/core-riscv64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let (prstatus, _) = synthesized(elf, b"crash-riscv64");
+    assert_eq!(prstatus.raw_registers().len(), 32 * 8 + 8);
+    let registers = prstatus.registers(elf.header().machine());
+    match registers {
+        Registers::Riscv(x) => assert_eq!((x.a0, x.a1), (0x5a454c46, 0x1234)),
+        _ => unreachable!(),
+    }
+    assert_eq!(registers.pc(), Some(0x10120));
+    assert_eq!(registers.sp(), Some(0x3ffffff000));
+}
+
+#[test]
+fn core_s390x() {
+    let bytes = std::fs::read("tests/data/core-s390x").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Big64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let (prstatus, _) = synthesized::<Big64>(elf, b"crash-s390x");
+    // registers of s390x are not decoded, but the header is read in big endian
+    let registers = prstatus.registers(elf.header().machine());
+    let Registers::Unknown(raw) = registers else {
+        unreachable!()
+    };
+    assert_eq!(raw.len(), 216 + 8);
+    // the PSW, which are the mask and the address, precedes "r0" to "r15"
+    assert_eq!(raw[8..16], 0x1000120u64.to_be_bytes());
+    assert_eq!(raw[32..40], 0x5a454c46u64.to_be_bytes());
+    assert_eq!(registers.pc(), None);
+}
+
+#[test]
+fn core_image() {
+    let bytes = std::fs::read("tests/data/core-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let image = CoreImage::parse(elf).unwrap();
+    let mut magic = [0u8; 4];
+    image.read(0x400000, &mut magic).unwrap();
+    assert_eq!(&magic, b"\x7fELF");
+    assert!(matches!(image.get(0x400ffe), Some(Memory::Present([_, _]))));
+    assert_eq!(image.get(0x401800), Some(Memory::Missing(0x800)));
+    let mut buffer = [0u8; 8];
+    assert!(matches!(
+        image.read(0x400ffc, &mut buffer),
+        Err(ReadCoreImageError::Missing(0x401000))
+    ));
+    assert!(matches!(
+        image.read(0x1234, &mut buffer),
+        Err(ReadCoreImageError::Unmapped(0x1234))
+    ));
+    let bytes = std::fs::read("tests/data/crash-x86_64").unwrap();
+    let build_id = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => BuildId::find(elf).unwrap(),
+        _ => unreachable!(),
+    };
+    let modules = image.modules().collect::<Vec<_>>();
+    assert_eq!(
+        modules,
+        [Module {
+            name: b"/tmp/zelf/crash-x86_64",
+            base: 0x400000,
+            end: 0x403000,
+            build_id: Some(build_id),
+        }]
+    );
+}
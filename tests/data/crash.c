@@ -0,0 +1,25 @@
+// Fixtures are built with:
+//
+// gcc -O2 -static -nostdlib -Wl,--build-id -o crash-x86_64 crash.c
+// gcc -m32 -O2 -static -nostdlib -Wl,--build-id -o crash-i386 crash.c
+//
+// Core dumps are generated with:
+//
+// ulimit -c unlimited; env -i ./crash-x86_64 zelf; mv core core-x86_64
+// ulimit -c unlimited; env -i ./crash-i386 zelf; mv core core-i386
+
+const char message[] = "zelf";
+
+// Writes to address "0x1234" with "ZELF" in the accumulator.
+__attribute__((noreturn)) void _start(void) {
+#if defined(__x86_64__)
+    __asm__ volatile("mov $0x1234, %rdi\n"
+                     "mov $0x5a454c46, %rax\n"
+                     "movl $42, (%rdi)\n");
+#elif defined(__i386__)
+    __asm__ volatile("mov $0x1234, %edi\n"
+                     "mov $0x5a454c46, %eax\n"
+                     "movl $42, (%edi)\n");
+#endif
+    __builtin_unreachable();
+}
@@ -0,0 +1,12 @@
+// Fixtures are built with:
+//
+// gcc -shared -fPIC -O2 -Wl,-soname,libzelf.so.1 -Wl,-rpath,'$ORIGIN/lib:/opt/zelf' -Wl,--enable-new-dtags -Wl,-z,now -Wl,-z,nodelete -Wl,--no-as-needed -o dynamic-x86_64 dynamic.c -lm
+// gcc -m32 -shared -fPIC -O2 -nostdlib -Wl,-soname,libzelf.so.1 -Wl,-rpath,/opt/zelf -Wl,--disable-new-dtags -o dynamic-i386 dynamic.c
+
+int initialized;
+
+__attribute__((constructor)) static void init(void) { initialized = 1; }
+
+__attribute__((destructor)) static void fini(void) { initialized = 0; }
+
+int answer(void) { return 42; }
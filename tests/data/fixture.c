@@ -0,0 +1,52 @@
+// Fixtures are built with:
+//
+// gcc -shared -fPIC -O2 -nostdlib -Wl,--hash-style=sysv -o sysv-x86_64 fixture.c
+// gcc -m32 -shared -fPIC -O2 -nostdlib -Wl,--hash-style=sysv -o sysv-i386 fixture.c
+// gcc -shared -fPIC -O2 -nostdlib -Wl,--hash-style=gnu -o gnu-x86_64 fixture.c
+// gcc -m32 -shared -fPIC -O2 -nostdlib -Wl,--hash-style=gnu -o gnu-i386 fixture.c
+// gcc -shared -fPIC -O2 -g -nostdlib -o debug-x86_64 fixture.c
+// objcopy --compress-debug-sections=zlib debug-x86_64 zlib-x86_64
+// objcopy --compress-debug-sections=zstd debug-x86_64 zstd-x86_64
+// objcopy --compress-debug-sections=zlib-gnu debug-x86_64 zlib-gnu-x86_64
+//
+// gcc -shared -fPIC -O0 -nostdlib -o minidebuginfo-x86_64 fixture.c
+// nm -D minidebuginfo-x86_64 --format=posix --defined-only | awk '{print $1}' | sort > dynsyms
+// nm minidebuginfo-x86_64 --format=posix --defined-only | awk '{ if ($2 == "T" || $2 == "t") print $1 }' | sort > funcsyms
+// comm -13 dynsyms funcsyms > keep
+// objcopy --only-keep-debug minidebuginfo-x86_64 mini
+// objcopy -S --remove-section .comment --keep-symbols=keep mini mini
+// strip --strip-all -R .comment minidebuginfo-x86_64
+// xz mini
+// objcopy --add-section .gnu_debugdata=mini.xz minidebuginfo-x86_64
+//
+// gcc -shared -fPIC -O0 -g -nostdlib -Wl,--build-id -o altlink-x86_64 fixture.c
+// gcc -shared -fPIC -O2 -g -nostdlib -Wl,--build-id -o debuglink-x86_64 fixture.c
+// objcopy --only-keep-debug debuglink-x86_64 debuglink-x86_64.debug
+// (printf 'altlink-x86_64\0'; readelf -n altlink-x86_64 | awk '/Build ID/{print $3}' | xxd -r -p) > altlink
+// objcopy --add-section .gnu_debugaltlink=altlink debuglink-x86_64.debug
+// objcopy --strip-debug --add-gnu-debuglink=debuglink-x86_64.debug debuglink-x86_64
+//
+// GNU as writes line number programs of DWARF 3 at least, so DWARF 2 is assembled by LLVM:
+// gcc -S -fPIC -O0 -g -gdwarf-2 -fdebug-prefix-map=$PWD=/zelf -o dwarf2.s fixture.c
+// llvm-mc -filetype=obj -triple=x86_64-linux-gnu -dwarf-version=2 -o dwarf2.o dwarf2.s
+// gcc -shared -nostdlib -o dwarf2-x86_64 dwarf2.o
+// gcc -shared -fPIC -O0 -g -gdwarf-3 -fdebug-prefix-map=$PWD=/zelf -nostdlib -o dwarf3-x86_64 fixture.c
+// gcc -shared -fPIC -O0 -g -gdwarf-4 -fdebug-prefix-map=$PWD=/zelf -nostdlib -o dwarf4-x86_64 fixture.c
+// gcc -shared -fPIC -O0 -g -gdwarf-5 -fdebug-prefix-map=$PWD=/zelf -nostdlib -o dwarf5-x86_64 fixture.c
+// gcc -m32 -shared -fPIC -O0 -g -gdwarf-5 -fdebug-prefix-map=$PWD=/zelf -nostdlib -o dwarf5-i386 fixture.c
+
+int counter;
+
+const char *message = "hello";
+
+int add(int a, int b) { return a + b; }
+
+int sub(int a, int b) { return a - b; }
+
+int mul(int a, int b) { return a * b; }
+
+int increase(void) { return ++counter; }
+
+static int hidden(int a) { return a * 3; }
+
+int call_hidden(int a) { return hidden(a) + 1; }
@@ -0,0 +1,30 @@
+// Fixtures are built with:
+//
+// gcc -shared -fPIC -O2 -nostdlib -Wl,--build-id -o gnu-note-x86_64 gnu-note.c
+// gcc -shared -fPIC -O2 -nostdlib -fuse-ld=gold -Wl,--build-id -o gold-x86_64 fixture.c
+
+// An ABI tag note, which is "Linux 3.2.0", as "crt1.o" contributes to executables.
+__asm__(".section .note.ABI-tag, \"a\", @note\n"
+        ".balign 4\n"
+        ".long 4\n"
+        ".long 16\n"
+        ".long 1\n"
+        ".string \"GNU\"\n"
+        ".long 0, 3, 2, 0\n"
+        ".previous\n");
+
+// A hardware capability note, as "ld.so.conf" of some distributions asks "ldconfig" to generate.
+__asm__(".section .note.hwcap, \"a\", @note\n"
+        ".balign 4\n"
+        ".long 4\n"
+        ".long 20\n"
+        ".long 2\n"
+        ".string \"GNU\"\n"
+        ".long 1\n"
+        ".long 1\n"
+        ".byte 0\n"
+        ".string \"nosegneg\"\n"
+        ".byte 0, 0\n"
+        ".previous\n");
+
+int answer(void) { return 42; }
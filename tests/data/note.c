@@ -0,0 +1,27 @@
+// Fixtures are built with:
+//
+// gcc -shared -fPIC -O2 -nostdlib -fcf-protection=full -Wl,-z,x86-64-v3 -Wl,--build-id -o note-x86_64 note.c
+
+// An ABI tag note, which is "Linux 3.2.0", as "crt1.o" contributes to executables.
+__asm__(".section .note.ABI-tag, \"a\", @note\n"
+        ".balign 4\n"
+        ".long 4\n"
+        ".long 16\n"
+        ".long 1\n"
+        ".string \"GNU\"\n"
+        ".long 0, 3, 2, 0\n"
+        ".previous\n");
+
+// A note whose name is not padded, in the same segment as the above.
+__asm__(".section .note.zelf, \"a\", @note\n"
+        ".balign 4\n"
+        ".long 5\n"
+        ".long 3\n"
+        ".long 0x1234\n"
+        ".string \"ZELF\"\n"
+        ".byte 0, 0, 0\n"
+        ".byte 1, 2, 3\n"
+        ".byte 0\n"
+        ".previous\n");
+
+int answer(void) { return 42; }
@@ -0,0 +1,29 @@
+; Fixtures are built with:
+;
+; llc -filetype=obj -relocation-model=pic -mtriple=x86_64-unknown-linux-gnu -o relocation-x86_64 relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=i386-unknown-linux-gnu -o relocation-i386 relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=aarch64-unknown-linux-gnu -o relocation-aarch64 relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=armv7-unknown-linux-gnueabihf -o relocation-arm relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=riscv32-unknown-linux-gnu -o relocation-riscv32 relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=riscv64-unknown-linux-gnu -o relocation-riscv64 relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=powerpc64le-unknown-linux-gnu -o relocation-ppc64le relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=mips-unknown-linux-gnu -o relocation-mips relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=mips64el-unknown-linux-gnuabi64 -o relocation-mips64el relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=mips64-unknown-linux-gnuabi64 -o relocation-mips64 relocation.ll
+; llc -filetype=obj -relocation-model=pic -mtriple=s390x-unknown-linux-gnu -o relocation-s390x relocation.ll
+
+@counter = external global i32
+@bytes = external global [16 x i8]
+
+@pointer = global i32* @counter
+@before = global i8* getelementptr ([16 x i8], [16 x i8]* @bytes, i64 0, i64 -8)
+@after = global i8* getelementptr ([16 x i8], [16 x i8]* @bytes, i64 0, i64 8)
+
+declare i32 @external(i32)
+
+define i32 @call(i32 %a) {
+  %x = load i32, i32* @counter
+  %y = call i32 @external(i32 %x)
+  %z = add i32 %y, %a
+  ret i32 %z
+}
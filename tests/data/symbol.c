@@ -0,0 +1,33 @@
+// Fixtures are built with:
+//
+// gcc -c -fPIC -O0 -fcommon -o symbol-x86_64 symbol.c
+
+static int local(void) { return 1; }
+
+extern int external(void);
+
+int global(void) { return local() + external(); }
+
+__attribute__((weak)) int weak(void) { return 3; }
+
+static int implementation(void) { return 4; }
+
+static int (*resolve(void))(void) { return implementation; }
+
+int indirect(void) __attribute__((ifunc("resolve")));
+
+__attribute__((visibility("hidden"))) int hidden(void) { return 5; }
+
+__attribute__((visibility("protected"))) int protected(void) { return 6; }
+
+__thread int tls;
+
+int common;
+
+// A unique object, as static data members of inline functions are in C++.
+__asm__(".section .data.unique, \"aw\", @progbits\n"
+        ".globl unique\n"
+        ".type unique, @gnu_unique_object\n"
+        ".size unique, 4\n"
+        "unique: .long 7\n"
+        ".previous\n");
@@ -0,0 +1,14 @@
+// Fixtures are built with:
+//
+// gcc -shared -fPIC -O2 -Wl,--version-script=version.map -Wl,--hash-style=both -o version-x86_64 version.c
+
+#include <stdio.h>
+
+int old_answer(void) { return 41; }
+
+int new_answer(void) { return 42; }
+
+__asm__(".symver old_answer, answer@VERS_1");
+__asm__(".symver new_answer, answer@@VERS_2");
+
+int greet(void) { return puts("hello"); }
@@ -0,0 +1,140 @@
+use std::path::{Path, PathBuf};
+use zelf::context::Little64;
+use zelf::debug_file::DebugFileResolver;
+use zelf::debug_link::{crc32, DebugAltLink, DebugLink};
+use zelf::elf::Elf;
+use zelf::gnu_note::BuildId;
+
+fn variant(bytes: &[u8]) -> zelf::elf::Variant<'_, Little64> {
+    match Elf::parse(bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    }
+}
+
+fn copy(from: &str, to: &Path) {
+    std::fs::create_dir_all(to.parent().unwrap()).unwrap();
+    std::fs::copy(format!("tests/data/{}", from), to).unwrap();
+}
+
+#[test]
+fn debug_link() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    let bytes = std::fs::read("tests/data/debuglink-x86_64").unwrap();
+    let link = DebugLink::find(variant(&bytes)).unwrap().unwrap();
+    assert_eq!(link.name(), b"debuglink-x86_64.debug");
+    assert_eq!(link.crc(), 0x741d18ff);
+    let debug = std::fs::read("tests/data/debuglink-x86_64.debug").unwrap();
+    assert!(link.check(&debug));
+    assert!(!link.check(&bytes));
+    assert!(DebugAltLink::find(variant(&bytes)).is_none());
+    let link = DebugAltLink::find(variant(&debug)).unwrap().unwrap();
+    assert_eq!(link.name(), b"altlink-x86_64");
+    let alt = std::fs::read("tests/data/altlink-x86_64").unwrap();
+    assert_eq!(Some(link.build_id()), BuildId::find(variant(&alt)));
+}
+
+#[test]
+fn debug_file_resolver() {
+    let root = std::env::temp_dir().join(format!("zelf-debug-file-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let root = {
+        std::fs::create_dir_all(&root).unwrap();
+        root.canonicalize().unwrap()
+    };
+    let path = root.join("bin/debuglink-x86_64");
+    copy("debuglink-x86_64", &path);
+    let bytes = std::fs::read(&path).unwrap();
+    let elf = variant(&bytes);
+    let build_id = BuildId::find(elf).unwrap();
+    assert_eq!(
+        format!("{}", build_id),
+        "8e70f8a33154b8ca79cbea6cd209856e72e987c1"
+    );
+    let directory = root.join("debug");
+    let resolver = DebugFileResolver::new(vec![directory.clone()]);
+    assert!(resolver.resolve(elf, &path).is_none());
+    // a debug file whose CRC-32 does not match
+    copy("debuglink-x86_64", &root.join("bin/debuglink-x86_64.debug"));
+    assert!(resolver.resolve(elf, &path).is_none());
+    // ".gnu_debuglink" in the global debug directory
+    let global = directory
+        .join(root.strip_prefix("/").unwrap())
+        .join("bin/debuglink-x86_64.debug");
+    copy("debuglink-x86_64.debug", &global);
+    assert_eq!(resolver.resolve(elf, &path).unwrap().path(), global);
+    // ".gnu_debuglink" in the ".debug" subdirectory
+    let subdirectory = root.join("bin/.debug/debuglink-x86_64.debug");
+    copy("debuglink-x86_64.debug", &subdirectory);
+    assert_eq!(resolver.resolve(elf, &path).unwrap().path(), subdirectory);
+    // build ID
+    let by_build_id = directory.join(".build-id/8e/70f8a33154b8ca79cbea6cd209856e72e987c1.debug");
+    copy("debuglink-x86_64.debug", &by_build_id);
+    let debug = resolver.resolve(elf, &path).unwrap();
+    assert_eq!(debug.path(), by_build_id);
+    assert_eq!(debug.build_id(), Some(build_id));
+    let sections = zelf::section::Sections::parse(debug.variant::<Little64>().unwrap())
+        .unwrap()
+        .unwrap();
+    assert!(sections.by_name(b".debug_info").is_some());
+    // ".gnu_debugaltlink" relative to the debug file
+    let elf = debug.variant::<Little64>().unwrap();
+    assert!(resolver.resolve_alt(elf, debug.path()).is_none());
+    let relative = directory.join(".build-id/8e/altlink-x86_64");
+    copy("altlink-x86_64", &relative);
+    assert_eq!(
+        resolver.resolve_alt(elf, debug.path()).unwrap().path(),
+        relative
+    );
+    // ".gnu_debugaltlink" by build ID
+    std::fs::remove_file(&relative).unwrap();
+    let alt = DebugAltLink::find(elf).unwrap().unwrap();
+    let by_build_id: PathBuf = directory.join(format!(
+        ".build-id/{:02x}/{}.debug",
+        alt.build_id().bytes()[0],
+        BuildId(&alt.build_id().bytes()[1..])
+    ));
+    copy("altlink-x86_64", &by_build_id);
+    assert_eq!(
+        resolver.resolve_alt(elf, debug.path()).unwrap().path(),
+        by_build_id
+    );
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn debug_link_escape() {
+    let root = std::env::temp_dir().join(format!("zelf-debug-link-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let path = root.join("bin/debuglink-x86_64");
+    copy("debuglink-x86_64", &path);
+    copy(
+        "debuglink-x86_64.debug",
+        &root.join("debuglink-x86_64.debug"),
+    );
+    copy("debuglink-x86_64.debug", &path.with_extension("debug"));
+    let crc = crc32(&std::fs::read("tests/data/debuglink-x86_64.debug").unwrap());
+    let link = |name: &str| {
+        let mut content = name.as_bytes().to_vec();
+        content.resize((name.len() + 4) / 4 * 4, 0);
+        content.extend(crc.to_le_bytes());
+        content
+    };
+    let resolver = DebugFileResolver::new(vec![]);
+    let absolute = root.join("debuglink-x86_64.debug");
+    for name in [
+        "../debuglink-x86_64.debug",
+        absolute.to_str().unwrap(),
+        "",
+        "..",
+    ] {
+        let content = link(name);
+        let link = DebugLink::parse::<Little64>(&content).unwrap();
+        assert!(resolver.by_debug_link(link, &path).is_none(), "{}", name);
+    }
+    let content = link("debuglink-x86_64.debug");
+    let link = DebugLink::parse::<Little64>(&content).unwrap();
+    assert!(resolver.by_debug_link(link, &path).is_some());
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,98 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use zelf::context::Little64;
+use zelf::debug_file::DebugFile;
+use zelf::debuginfod::{Artifact, DebuginfodClient, DebuginfodError, Transport};
+use zelf::elf::Elf;
+use zelf::gnu_note::BuildId;
+
+/// Stand-in server, which records requests.
+#[derive(Default)]
+struct Local {
+    files: HashMap<String, Vec<u8>>,
+    requests: RefCell<Vec<String>>,
+}
+
+impl Transport for Local {
+    type Error = String;
+
+    fn get(&self, url: &str) -> Result<Option<Vec<u8>>, String> {
+        self.requests.borrow_mut().push(url.to_string());
+        if url.starts_with("http://down") {
+            return Err(String::from("connection refused"));
+        }
+        Ok(self.files.get(url).cloned())
+    }
+}
+
+#[test]
+fn debuginfod() {
+    let cache = std::env::temp_dir().join(format!("zelf-debuginfod-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache);
+    let bytes = std::fs::read("tests/data/debuglink-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let id = "8e70f8a33154b8ca79cbea6cd209856e72e987c1";
+    let debug = std::fs::read("tests/data/debuglink-x86_64.debug").unwrap();
+    let mut local = Local::default();
+    let url = |x: &str| format!("http://local/buildid/{}/{}", id, x);
+    local.files.insert(url("debuginfo"), debug.clone());
+    local
+        .files
+        .insert(url("source/src/a%20b.c"), b"int a;".to_vec());
+    local
+        .files
+        .insert(url("executable"), b"not an ELF file".to_vec());
+    local.files.insert(
+        String::from("http://local/buildid/0102/debuginfo"),
+        debug.clone(),
+    );
+    let urls = vec![String::from("http://down"), String::from("http://local/")];
+    let client = DebuginfodClient::new(local, urls, cache.clone());
+    // debuginfo
+    let path = client.debuginfo_for(elf).unwrap().unwrap();
+    assert_eq!(path, cache.join(id).join("debuginfo"));
+    assert_eq!(std::fs::read(&path).unwrap(), debug);
+    let file = DebugFile::open(path.clone()).unwrap();
+    assert!(file.variant::<Little64>().is_some());
+    assert_eq!(
+        *client.transport().requests.borrow(),
+        [
+            format!("http://down/buildid/{}/debuginfo", id),
+            url("debuginfo")
+        ]
+    );
+    // cached
+    let build_id = BuildId::find(elf).unwrap();
+    assert_eq!(client.debuginfo(build_id).unwrap().unwrap(), path);
+    assert_eq!(client.transport().requests.borrow().len(), 2);
+    // not an ELF file
+    assert!(client.executable(build_id).unwrap().is_none());
+    assert_eq!(client.transport().requests.borrow().len(), 4);
+    assert!(!cache.join(id).join("executable").exists());
+    // another build ID
+    assert!(client.debuginfo(BuildId(&[1, 2])).unwrap().is_none());
+    assert!(!cache.join("0102").exists());
+    // source
+    let path = client.source(build_id, "/src/a b.c").unwrap().unwrap();
+    assert_eq!(path, cache.join(id).join("source#src#a b.c"));
+    assert_eq!(std::fs::read(&path).unwrap(), b"int a;");
+    assert!(matches!(
+        client.fetch(build_id, Artifact::Source("src/a b.c")),
+        Err(DebuginfodError::BadPropertyPath)
+    ));
+    assert!(matches!(
+        client.fetch(BuildId(&[]), Artifact::Debuginfo),
+        Err(DebuginfodError::BadPropertyBuildId)
+    ));
+    // every server fails
+    let urls = vec![String::from("http://down")];
+    let client = DebuginfodClient::new(Local::default(), urls, cache.clone());
+    assert!(matches!(
+        client.executable(build_id),
+        Err(DebuginfodError::FromTransport(_))
+    ));
+    std::fs::remove_dir_all(&cache).unwrap();
+}
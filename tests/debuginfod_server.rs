@@ -0,0 +1,153 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use zelf::debuginfod::{Artifact, DebuginfodClient, Transport};
+use zelf::debuginfod_server::{DebuginfodIndex, FileKind, MAX_SCAN_DEPTH};
+use zelf::elf::Elf;
+use zelf::gnu_note::BuildId;
+
+/// HTTP/1.0 over TCP, which is enough for loopback.
+struct Http;
+
+impl Transport for Http {
+    type Error = std::io::Error;
+
+    fn get(&self, url: &str) -> std::io::Result<Option<Vec<u8>>> {
+        let rest = url.strip_prefix("http://").unwrap();
+        let (host, path) = rest.split_at(rest.find('/').unwrap());
+        let mut stream = TcpStream::connect(host)?;
+        write!(stream, "GET {} HTTP/1.0\r\nHost: {}\r\n\r\n", path, host)?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+        let end = response.windows(4).position(|x| x == b"\r\n\r\n").unwrap();
+        match &response[..12] {
+            b"HTTP/1.0 200" => Ok(Some(response[end + 4..].to_vec())),
+            b"HTTP/1.0 404" => Ok(None),
+            _ => Err(std::io::Error::other("bad response")),
+        }
+    }
+}
+
+fn kind(file: &str) -> FileKind {
+    let bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+    match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => FileKind::classify(elf),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn file_kind() {
+    let kind = |x, executable, debuginfo| {
+        let expected = FileKind {
+            executable,
+            debuginfo,
+        };
+        assert_eq!(kind(x), expected, "{}", x);
+    };
+    kind("debug-x86_64", true, true);
+    kind("debuglink-x86_64", true, false);
+    kind("debuglink-x86_64.debug", false, true);
+    kind("crash-x86_64", true, false);
+    kind("core-x86_64", false, false);
+}
+
+#[test]
+fn debuginfod_server() {
+    let root = std::env::temp_dir().join(format!("zelf-debuginfod-server-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    for (from, to) in [
+        ("debuglink-x86_64", "bin/debuglink-x86_64"),
+        ("debuglink-x86_64.debug", "debug/debuglink-x86_64.debug"),
+        ("debug-x86_64", "lib/debug-x86_64"),
+        ("core-x86_64", "core"),
+        ("fixture.c", "fixture.c"),
+    ] {
+        let to = root.join("tree").join(to);
+        std::fs::create_dir_all(to.parent().unwrap()).unwrap();
+        std::fs::copy(format!("tests/data/{}", from), to).unwrap();
+    }
+    let mut index = DebuginfodIndex::new();
+    assert_eq!(index.scan(&root.join("tree")).unwrap(), 3);
+    assert_eq!(index.len(), 2);
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || index.serve(&listener, 2));
+    let url = format!("http://{}", address);
+    let client = DebuginfodClient::new(Http, vec![url], root.join("cache"));
+    let fetch = |id: &str, artifact| {
+        let id = (0..id.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&id[i..i + 2], 16).unwrap())
+            .collect::<Vec<u8>>();
+        let path = client.fetch(BuildId(&id), artifact).unwrap();
+        path.map(|x| std::fs::read(x).unwrap())
+    };
+    let read = |x: &str| Some(std::fs::read(format!("tests/data/{}", x)).unwrap());
+    let debuglink = "8e70f8a33154b8ca79cbea6cd209856e72e987c1";
+    let debug = "2941bbf949ffe8f5c58d65a2dca5d580cb74cc55";
+    let missing = "4068d944ee750b98b29f2cd18d7cff9b5c08649d";
+    let (debuginfo, executable) = (Artifact::Debuginfo, Artifact::Executable);
+    let source = Artifact::Source("/fixture.c");
+    assert_eq!(fetch(debuglink, debuginfo), read("debuglink-x86_64.debug"));
+    assert_eq!(fetch(debuglink, executable), read("debuglink-x86_64"));
+    assert_eq!(fetch(debuglink, source), None);
+    assert_eq!(fetch(debug, debuginfo), read("debug-x86_64"));
+    assert_eq!(fetch(debug, executable), read("debug-x86_64"));
+    assert_eq!(fetch(missing, debuginfo), None);
+    // bad requests
+    let path = format!("/buildid/{}/debuginfo", debug);
+    let long = format!("GET {}?{} HTTP/1.0\r\n\r\n", path, "a".repeat(8192));
+    let many = format!("GET {} HTTP/1.0\r\n{}\r\n", path, "X: x\r\n".repeat(65));
+    let unfinished = format!("GET {} HTTP/1.0\r\nX: x\r\n", path);
+    for request in [
+        "POST /buildid HTTP/1.0\r\n\r\n",
+        "\r\n\r\n",
+        &long,
+        &many,
+        &unfinished,
+    ] {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        stream.shutdown(std::net::Shutdown::Write).unwrap();
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).unwrap();
+        assert!(response.starts_with(b"HTTP/1.0 400"));
+    }
+    // headers up to the limit
+    let mut stream = TcpStream::connect(address).unwrap();
+    let request = format!("HEAD {} HTTP/1.0\r\n{}\r\n", path, "X: x\r\n".repeat(64));
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).unwrap();
+    assert!(response.starts_with(b"HTTP/1.0 200"));
+    // "HEAD" gives the size of the file without its content
+    let size = read("debug-x86_64").unwrap().len();
+    let length = format!("Content-Length: {}\r\n\r\n", size);
+    assert!(response.ends_with(length.as_bytes()));
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn scan() {
+    let root = std::env::temp_dir().join(format!("zelf-debuginfod-scan-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let mut deep = root.clone();
+    for i in 0..=MAX_SCAN_DEPTH {
+        deep.push(i.to_string());
+        if i == MAX_SCAN_DEPTH - 1 {
+            std::fs::create_dir_all(&deep).unwrap();
+            std::fs::copy("tests/data/debug-x86_64", deep.join("debug-x86_64")).unwrap();
+        }
+    }
+    // beyond the depth limit
+    std::fs::create_dir_all(&deep).unwrap();
+    std::fs::copy("tests/data/crash-x86_64", deep.join("crash-x86_64")).unwrap();
+    // a loop of symbolic links, which are not followed
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&root, root.join("0").join("loop")).unwrap();
+    let mut index = DebuginfodIndex::new();
+    assert_eq!(index.scan(&root).unwrap(), 1);
+    assert_eq!(index.len(), 1);
+    assert!(index.scan(&root.join("missing")).is_err());
+    std::fs::remove_dir_all(&root).unwrap();
+}
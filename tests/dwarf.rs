@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+use zelf::context::{Context, Little32, Little64};
+use zelf::dwarf::{DebugLine, LineFile, Location, ParseLineProgramError};
+use zelf::elf::{Elf, Variant};
+use zelf::section::Sections;
+
+struct Debug<'a> {
+    line: Cow<'a, [u8]>,
+    line_str: Cow<'a, [u8]>,
+    str: Cow<'a, [u8]>,
+}
+
+fn debug<T: Context>(elf: Variant<'_, T>) -> Debug<'_> {
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let section = |x: &[u8]| match sections.decompressed_by_name(x) {
+        Some(x) => x.unwrap(),
+        None => Cow::Borrowed(&[][..]),
+    };
+    Debug {
+        line: section(b".debug_line"),
+        line_str: section(b".debug_line_str"),
+        str: section(b".debug_str"),
+    }
+}
+
+const EXPECTED: [(u64, Option<u64>); 10] = [
+    (0x1000, Some(38)),
+    (0x1013, Some(38)),
+    (0x1014, Some(40)),
+    (0x1050, Some(44)),
+    (0x1070, Some(48)),
+    (0x1088, Some(48)),
+    (0x1089, None),
+    (0x10a0, None),
+    (0x10a1, None),
+    (0x2000, None),
+];
+
+const EXPECTED_I386: [(u64, Option<u64>); 10] = [
+    (0x1000, Some(38)),
+    (0x1013, Some(38)),
+    (0x1014, Some(38)),
+    (0x1050, Some(44)),
+    (0x1070, Some(46)),
+    (0x1088, Some(48)),
+    (0x1089, Some(48)),
+    (0x10a0, Some(48)),
+    (0x10a1, None),
+    (0x2000, None),
+];
+
+fn check<T: Context>(debug: &Debug, version: u16, expected: &[(u64, Option<u64>)]) {
+    let debug_line = DebugLine::<T>::new(&debug.line, &debug.line_str, &debug.str);
+    let programs = debug_line
+        .programs()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(programs.len(), 1);
+    let program = programs[0];
+    assert_eq!(program.version(), version);
+    assert_eq!(debug_line.program(0).unwrap().version(), version);
+    assert!(matches!(
+        debug_line.program(debug.line.len()),
+        Err(ParseLineProgramError::BrokenHeader)
+    ));
+    let file = match version {
+        5 => {
+            assert_eq!(program.directories(), 1);
+            assert_eq!(program.directory(0), Some(&b"/zelf"[..]));
+            assert_eq!(program.files(), 2);
+            LineFile {
+                directory: Some(b"/zelf"),
+                name: b"fixture.c",
+            }
+        }
+        _ => {
+            assert_eq!(program.directories(), 0);
+            assert_eq!(program.directory(0), None);
+            assert_eq!(program.files(), 1);
+            assert_eq!(program.file(0), None);
+            LineFile {
+                directory: None,
+                name: b"fixture.c",
+            }
+        }
+    };
+    assert_eq!(program.file(1), Some(file));
+    for &(address, line) in expected {
+        let location = debug_line.find(address).unwrap();
+        assert_eq!(location.map(|x| x.line), line, "{:#x}", address);
+        if let Some(location) = location {
+            assert_eq!(location.file, Some(file));
+        }
+    }
+    // the first row of "add"
+    assert_eq!(
+        debug_line.find(0x1000).unwrap(),
+        Some(Location {
+            file: Some(file),
+            line: 38,
+            column: 23,
+        })
+    );
+    let rows = program.rows().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(rows.iter().filter(|x| x.end_sequence).count(), 1);
+    assert!(rows.windows(2).all(|x| x[0].address <= x[1].address));
+}
+
+#[test]
+fn debug_line() {
+    for (file, version) in [
+        ("dwarf2-x86_64", 2),
+        ("dwarf3-x86_64", 3),
+        ("dwarf4-x86_64", 4),
+        ("dwarf5-x86_64", 5),
+    ] {
+        let bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+        let elf = match Elf::parse(&bytes).unwrap() {
+            Elf::Little64(elf) => elf,
+            _ => unreachable!(),
+        };
+        check::<Little64>(&debug(elf), version, &EXPECTED);
+    }
+    let bytes = std::fs::read("tests/data/dwarf5-i386").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little32(elf) => elf,
+        _ => unreachable!(),
+    };
+    check::<Little32>(&debug(elf), 5, &EXPECTED_I386);
+}
+
+#[test]
+fn debug_line_version() {
+    let bytes = std::fs::read("tests/data/dwarf3-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let mut debug = debug(elf);
+    // the header of DWARF 2 is the same as DWARF 3
+    debug.line.to_mut()[4..6].copy_from_slice(&2u16.to_le_bytes());
+    check::<Little64>(&debug, 2, &EXPECTED);
+    debug.line.to_mut()[4..6].copy_from_slice(&6u16.to_le_bytes());
+    let debug_line = DebugLine::<Little64>::new(&debug.line, &[], &[]);
+    assert!(matches!(
+        debug_line.find(0x1000),
+        Err(ParseLineProgramError::BadPropertyVersion)
+    ));
+}
+
+#[test]
+fn debug_line_compressed() {
+    let read = |file: &str| std::fs::read(format!("tests/data/{}", file)).unwrap();
+    let (bytes, zlib, zstd) = (
+        read("debug-x86_64"),
+        read("zlib-x86_64"),
+        read("zstd-x86_64"),
+    );
+    let variant = |bytes| match Elf::parse(bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let debugs = [
+        debug(variant(&bytes)),
+        debug(variant(&zlib)),
+        debug(variant(&zstd)),
+    ];
+    let debug_lines = debugs
+        .iter()
+        .map(|x| DebugLine::<Little64>::new(&x.line, &x.line_str, &x.str))
+        .collect::<Vec<_>>();
+    for (address, line) in [(0x1000, 12), (0x1010, 14), (0x1020, 16), (0x1030, 18)] {
+        let expected = debug_lines[0].find(address).unwrap();
+        assert_eq!(expected.map(|x| x.line), Some(line));
+    }
+    for address in 0x1000..0x1050 {
+        let expected = debug_lines[0].find(address).unwrap();
+        for x in &debug_lines[1..] {
+            assert_eq!(x.find(address).unwrap(), expected);
+        }
+    }
+}
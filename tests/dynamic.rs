@@ -0,0 +1,180 @@
+use zelf::context::{Context, Little32, Little64};
+use zelf::dynamic::{Dynamic, DynamicFlags1, DynamicFlags32, DynamicFlags64, DynamicTag64};
+use zelf::dynamic::{DYNAMIC_TAG_GNU_HASH, DYNAMIC_TAG_VERSYM};
+use zelf::dynamic_info::{DynamicInfo, DynamicView, PltRelocations};
+use zelf::elf::{Elf, Variant};
+use zelf::program::Programs;
+use zelf::section::Sections;
+use zelf::symbols::Symbols;
+use zelf::version::Versions;
+
+#[test]
+fn iter() {
+    let bytes = std::fs::read("tests/data/dynamic-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let mut content = sections.by_name(b".dynamic").unwrap().content().to_vec();
+    let dynamic = Dynamic::<Little64>::parse(&content).unwrap();
+    // "DT_NULL" is followed by padding entries
+    assert_eq!(dynamic.entries().len(), 30);
+    assert_eq!(dynamic.iter().count(), 25);
+    assert!(dynamic.iter().all(|x| x.tag() != DynamicTag64::Null));
+    // an entry after the first "DT_NULL" is not visited
+    let null = 25 * 16;
+    content[null + 16..null + 24].copy_from_slice(&1u64.to_le_bytes());
+    let dynamic = Dynamic::<Little64>::parse(&content).unwrap();
+    assert_eq!(dynamic.entries()[26].tag(), DynamicTag64::Needed);
+    assert_eq!(dynamic.iter().count(), 25);
+    let bytes = std::fs::read("tests/data/dynamic-i386").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little32(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let content = sections.by_name(b".dynamic").unwrap().content();
+    let dynamic = Dynamic::<Little32>::parse(content).unwrap();
+    assert_eq!(dynamic.entries().len(), 20);
+    assert_eq!(dynamic.iter().count(), 15);
+}
+
+#[test]
+fn dynamic_info() {
+    let bytes = std::fs::read("tests/data/dynamic-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let info = DynamicInfo::parse(elf).unwrap().unwrap();
+    assert_eq!(
+        info.needed().collect::<Vec<_>>(),
+        [&b"libm.so.6"[..], b"libc.so.6"]
+    );
+    assert_eq!(info.soname(), Some(&b"libzelf.so.1"[..]));
+    assert_eq!(info.rpath(), None);
+    assert_eq!(info.runpath(), Some(&b"$ORIGIN/lib:/opt/zelf"[..]));
+    assert_eq!(info.flags(), Some(DynamicFlags64::BIND_NOW));
+    assert_eq!(
+        info.flags_1(),
+        Some(DynamicFlags1::NOW | DynamicFlags1::NODELETE)
+    );
+    assert_eq!(info.init_array().map(|x| x.1), Some(16));
+    assert_eq!(info.fini_array().map(|x| x.1), Some(16));
+    assert_eq!(info.preinit_array(), None);
+    let bytes = std::fs::read("tests/data/dynamic-i386").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little32(elf) => elf,
+        _ => unreachable!(),
+    };
+    let info = DynamicInfo::parse(elf).unwrap().unwrap();
+    assert_eq!(info.needed().count(), 0);
+    assert_eq!(info.soname(), Some(&b"libzelf.so.1"[..]));
+    assert_eq!(info.rpath(), Some(&b"/opt/zelf"[..]));
+    assert_eq!(info.runpath(), None);
+    assert_eq!(info.flags(), None::<DynamicFlags32>);
+    assert_eq!(info.init_array(), Some((0x3f48, 4)));
+    assert_eq!(info.fini_array(), Some((0x3f4c, 4)));
+    // tags of 32-bit entries are widened, so they are found by the same typed tags
+    assert_eq!(info.find(DYNAMIC_TAG_GNU_HASH), Some(0x178));
+    assert_eq!(info.find(DYNAMIC_TAG_VERSYM), None);
+    assert_eq!(info.find(DynamicTag64::RelSize), Some(24));
+    let tags = info
+        .dynamic()
+        .iter()
+        .map(|x| x.checked_tag().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(tags[6], DYNAMIC_TAG_GNU_HASH);
+    assert_eq!(tags[14], DynamicTag64::OsSpecific(0x6ffffffa));
+}
+
+/// Removes section headers and everything beyond programs, as "sstrip" does.
+fn sstrip<T: Context>(elf: Variant<T>) -> Vec<u8> {
+    let programs = Programs::parse(elf).unwrap().unwrap();
+    let end = programs
+        .iter()
+        .map(|(_, x)| {
+            let x = x.unwrap();
+            Into::<u64>::into(x.header().offset()) + Into::<u64>::into(x.header().filesz())
+        })
+        .max()
+        .unwrap();
+    let mut bytes = elf.data()[..end as usize].to_vec();
+    // "e_shoff", and then "e_shnum" and "e_shstrndx"
+    let (shoff, shnum) = match bytes[4] {
+        1 => (0x20..0x24, 0x30..0x34),
+        _ => (0x28..0x30, 0x3c..0x40),
+    };
+    bytes[shoff].fill(0);
+    bytes[shnum].fill(0);
+    bytes
+}
+
+fn compare<'a, T: Context>(elf: Variant<'a, T>, stripped: Variant<'a, T>) {
+    assert!(Sections::parse(stripped).unwrap().is_none());
+    let view = DynamicView::parse(stripped).unwrap().unwrap();
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let dynsym = sections.by_name(b".dynsym").unwrap();
+    let expected = Symbols::parse(sections, dynsym).unwrap();
+    let symbols = view.symbols().unwrap();
+    assert_eq!(symbols.num(), expected.num());
+    let names = |x: Symbols<'a, T>| x.iter().map(|x| x.name()).collect::<Vec<_>>();
+    assert_eq!(names(symbols), names(expected));
+    let globals = |x: Symbols<'a, T>| x.globals().map(|x| x.index()).collect::<Vec<_>>();
+    assert_eq!(globals(symbols), globals(expected));
+    match (view.versions(), Versions::parse(sections).unwrap()) {
+        (Some(versions), Some(expected)) => {
+            for index in 0..symbols.num() {
+                assert_eq!(versions.get(index), expected.get(index));
+            }
+        }
+        (None, None) => (),
+        _ => unreachable!(),
+    }
+    let size = |name: &[u8]| sections.by_name(name).map(|x| x.content().len());
+    let rela = view.rela().map(|x| std::mem::size_of_val(x.entries()));
+    assert_eq!(rela, size(b".rela.dyn"));
+    let rel = view.rel().map(|x| std::mem::size_of_val(x.entries()));
+    assert_eq!(rel, size(b".rel.dyn"));
+    let plt = view.plt().map(|x| match x {
+        PltRelocations::Rel(x) => std::mem::size_of_val(x.entries()),
+        PltRelocations::Rela(x) => std::mem::size_of_val(x.entries()),
+    });
+    assert_eq!(plt, size(b".rela.plt").or(size(b".rel.plt")));
+    assert_eq!(view.hash().is_some(), size(b".hash").is_some());
+    assert_eq!(view.gnu_hash().is_some(), size(b".gnu.hash").is_some());
+}
+
+#[test]
+fn dynamic_view() {
+    for file in [
+        "version-x86_64",
+        "sysv-x86_64",
+        "gnu-x86_64",
+        "dynamic-x86_64",
+    ] {
+        let bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+        let elf = match Elf::parse(&bytes).unwrap() {
+            Elf::Little64(elf) => elf,
+            _ => unreachable!(),
+        };
+        let stripped = sstrip(elf);
+        match Elf::parse(&stripped).unwrap() {
+            Elf::Little64(x) => compare(elf, x),
+            _ => unreachable!(),
+        }
+    }
+    for file in ["sysv-i386", "gnu-i386", "dynamic-i386"] {
+        let bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+        let elf = match Elf::parse(&bytes).unwrap() {
+            Elf::Little32(elf) => elf,
+            _ => unreachable!(),
+        };
+        let stripped = sstrip(elf);
+        match Elf::parse(&stripped).unwrap() {
+            Elf::Little32(x) => compare(elf, x),
+            _ => unreachable!(),
+        }
+    }
+}
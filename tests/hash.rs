@@ -0,0 +1,103 @@
+use zelf::context::Context;
+use zelf::context::Little64;
+use zelf::elf::Elf;
+use zelf::elf::Variant;
+use zelf::gnu_hash::{GnuHash, ParseGnuHashError};
+use zelf::hash::Hash;
+use zelf::section::Sections;
+use zelf::strtab::Strtab;
+use zelf::symtab::Symtab;
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Sysv,
+    Gnu,
+}
+
+type Lookup<'a> = Box<dyn Fn(&[u8]) -> Option<usize> + 'a>;
+
+fn check<T: Context>(elf: Variant<T>, kind: Kind) {
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let symtab = Symtab::<T>::parse(sections.by_name(b".dynsym").unwrap().content()).unwrap();
+    let strtab = Strtab::parse(sections.by_name(b".dynstr").unwrap().content()).unwrap();
+    // symbols before "symoffset" are not in the GNU hash table
+    let (symoffset, lookup): (usize, Lookup) = match kind {
+        Kind::Sysv => {
+            let hash = Hash::<T>::parse(sections.by_name(b".hash").unwrap().content()).unwrap();
+            assert_eq!(hash.chains().len(), symtab.entries().len());
+            (
+                0,
+                Box::new(move |x| hash.lookup(x, symtab, strtab).map(|x| x.0)),
+            )
+        }
+        Kind::Gnu => {
+            let hash =
+                GnuHash::<T>::parse(sections.by_name(b".gnu.hash").unwrap().content()).unwrap();
+            assert_eq!(hash.symbols_num(), Some(symtab.entries().len()));
+            (
+                hash.header().symoffset() as usize,
+                Box::new(move |x| hash.lookup(x, symtab, strtab).map(|x| x.0)),
+            )
+        }
+    };
+    let mut found = 0;
+    for (index, entry) in symtab.iter().filter(|(i, _)| *i >= symoffset) {
+        let name = strtab.find(entry.name() as usize).unwrap();
+        assert_eq!(lookup(name), Some(index));
+        found += 1;
+    }
+    assert!(found >= 6);
+    assert!(lookup(b"add").is_some());
+    assert!(lookup(b"hidden").is_none());
+    assert!(lookup(b"").is_none());
+}
+
+#[test]
+fn sysv_hash() {
+    for file in ["tests/data/sysv-x86_64", "tests/data/sysv-i386"] {
+        let bytes = std::fs::read(file).unwrap();
+        match Elf::parse(&bytes).unwrap() {
+            Elf::Little32(elf) => check(elf, Kind::Sysv),
+            Elf::Little64(elf) => check(elf, Kind::Sysv),
+            Elf::Big32(elf) => check(elf, Kind::Sysv),
+            Elf::Big64(elf) => check(elf, Kind::Sysv),
+        }
+    }
+}
+
+#[test]
+fn gnu_hash() {
+    for file in ["tests/data/gnu-x86_64", "tests/data/gnu-i386"] {
+        let bytes = std::fs::read(file).unwrap();
+        match Elf::parse(&bytes).unwrap() {
+            Elf::Little32(elf) => check(elf, Kind::Gnu),
+            Elf::Little64(elf) => check(elf, Kind::Gnu),
+            Elf::Big32(elf) => check(elf, Kind::Gnu),
+            Elf::Big64(elf) => check(elf, Kind::Gnu),
+        }
+    }
+}
+
+#[test]
+fn gnu_hash_bloom_shift() {
+    let bytes = std::fs::read("tests/data/gnu-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let symtab = Symtab::parse(sections.by_name(b".dynsym").unwrap().content()).unwrap();
+    let strtab = Strtab::parse(sections.by_name(b".dynstr").unwrap().content()).unwrap();
+    let mut content = sections.by_name(b".gnu.hash").unwrap().content().to_vec();
+    // the largest shift of a 32-bit hash value
+    content[12..16].copy_from_slice(&31u32.to_le_bytes());
+    let hash = GnuHash::<Little64>::parse(&content).unwrap();
+    for name in [&b"add"[..], b"hidden", b""] {
+        let _ = hash.lookup(name, symtab, strtab);
+    }
+    content[12..16].copy_from_slice(&40u32.to_le_bytes());
+    assert!(matches!(
+        GnuHash::<Little64>::parse(&content),
+        Err(ParseGnuHashError::BadPropertyBloomShift)
+    ));
+}
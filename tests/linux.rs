@@ -8,9 +8,8 @@ fn show(file: &str) -> Result<(), Box<dyn Error>> {
     use zelf::elf::Elf::{self, *};
     use zelf::ident::ParseIdentError::*;
     let bytes = std::fs::read(file)?;
-    match Ident::parse(&bytes) {
-        Err(BrokenHeader | BadPropertyMagic) => return Ok(()),
-        _ => (),
+    if let Err(BrokenHeader | BadPropertyMagic) = Ident::parse(&bytes) {
+        return Ok(());
     }
     println!("File Name: {}", file);
     match Elf::parse(&bytes).unwrap() {
@@ -25,13 +24,13 @@ fn show(file: &str) -> Result<(), Box<dyn Error>> {
 #[cfg(target_os = "linux")]
 #[test]
 fn linux() {
-    let usr_lib = std::fs::read_dir("/usr/lib").unwrap().into_iter();
-    let usr_bin = std::fs::read_dir("/usr/bin").unwrap().into_iter();
+    let usr_lib = std::fs::read_dir("/usr/lib").unwrap();
+    let usr_bin = std::fs::read_dir("/usr/bin").unwrap();
     for each in usr_lib.chain(usr_bin) {
         let each = each.unwrap();
         if !each.file_type().unwrap().is_file() {
             continue;
         }
-        if let Err(_) = show(&format!("/usr/bin/{}", each.file_name().to_str().unwrap())) {}
+        let _ = show(&format!("/usr/bin/{}", each.file_name().to_str().unwrap()));
     }
 }
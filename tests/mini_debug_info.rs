@@ -0,0 +1,64 @@
+use zelf::context::{Big64, Little64};
+use zelf::elf::Elf;
+use zelf::mini_debug_info::{MiniDebugInfo, ParseMiniDebugInfoError};
+use zelf::section::Sections;
+use zelf::symbols::Symbols;
+
+#[test]
+fn mini_debug_info() {
+    let bytes = std::fs::read("tests/data/minidebuginfo-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    assert!(sections.by_name(b".symtab").is_none());
+    let dynsym = sections.by_name(b".dynsym").unwrap();
+    let dynsym = Symbols::parse(sections, dynsym).unwrap();
+    let mini = MiniDebugInfo::find(elf, 1 << 20).unwrap().unwrap();
+    assert!(matches!(mini.elf(), Elf::Little64(_)));
+    assert!(mini.variant::<Big64>().is_none());
+    let size = mini.data().len();
+    assert!(MiniDebugInfo::find(elf, size).unwrap().is_ok());
+    assert!(matches!(
+        MiniDebugInfo::find(elf, size - 1).unwrap(),
+        Err(ParseMiniDebugInfoError::TooLarge)
+    ));
+    let nested = mini.variant::<Little64>().unwrap();
+    let nested = Sections::parse(nested).unwrap().unwrap();
+    let symtab = nested.by_name(b".symtab").unwrap();
+    let symtab = Symbols::parse(nested, symtab).unwrap();
+    let mut names = dynsym
+        .iter()
+        .chain(symtab.iter())
+        .filter_map(|x| x.name())
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    assert_eq!(
+        names,
+        [
+            &b"add"[..],
+            b"call_hidden",
+            b"counter",
+            b"hidden",
+            b"increase",
+            b"message",
+            b"mul",
+            b"sub"
+        ]
+    );
+    assert!(dynsym.iter().all(|x| x.name() != Some(b"hidden")));
+    // no ".gnu_debugdata"
+    let bytes = std::fs::read("tests/data/debug-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    assert!(MiniDebugInfo::find(elf, 1 << 20).is_none());
+    assert!(matches!(
+        MiniDebugInfo::parse(b"\xfd7zXZ\0", 1 << 20),
+        Err(ParseMiniDebugInfoError::BrokenXz)
+    ));
+}
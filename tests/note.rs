@@ -0,0 +1,123 @@
+use zelf::context::Little64;
+use zelf::elf::Elf;
+use zelf::gnu_note::{AbiTag, AbiTagOs, BuildId, GnuNote};
+use zelf::gnu_property::{GnuProperties, GnuProperty, X86Feature1, X86Isa1};
+use zelf::note::Notes;
+use zelf::program::{ProgramType, Programs};
+
+#[test]
+fn notes() {
+    let bytes = std::fs::read("tests/data/note-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let programs = Programs::parse(elf).unwrap().unwrap();
+    let mut notes = Vec::new();
+    for (_, program) in programs.iter() {
+        let program = program.unwrap();
+        if program.header().typa() != ProgramType::Note {
+            continue;
+        }
+        let parsed = Notes::<Little64>::parse(program.content(), program.header().align()).unwrap();
+        assert_eq!(parsed.alignment() as u64, program.header().align());
+        notes.extend(
+            parsed
+                .iter()
+                .map(|x| (x.name(), x.typa(), x.descriptor().len())),
+        );
+    }
+    assert_eq!(
+        notes,
+        [
+            (&b"GNU"[..], 5, 32),
+            (b"GNU", 3, 20),
+            (b"GNU", 1, 16),
+            (b"ZELF", 0x1234, 3)
+        ]
+    );
+}
+
+#[test]
+fn gnu_notes() {
+    let bytes = std::fs::read("tests/data/gnu-note-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let build_id = BuildId::find(elf).unwrap();
+    assert_eq!(
+        format!("{}", build_id),
+        "8d1f7f8bb4509a4436d41f9399439c4d86495c30"
+    );
+    let programs = Programs::parse(elf).unwrap().unwrap();
+    let mut abi_tag = None;
+    let mut hwcaps = Vec::new();
+    for (_, program) in programs.iter() {
+        let program = program.unwrap();
+        if program.header().typa() != ProgramType::Note {
+            continue;
+        }
+        let notes = Notes::<Little64>::parse(program.content(), program.header().align()).unwrap();
+        for note in notes.iter() {
+            match GnuNote::parse::<Little64>(note).unwrap() {
+                Some(GnuNote::AbiTag(x)) => abi_tag = Some(x),
+                Some(GnuNote::Hwcap(x)) => hwcaps.extend(x.iter()),
+                Some(_) => (),
+                None => unreachable!(),
+            }
+        }
+    }
+    assert_eq!(
+        abi_tag,
+        Some(AbiTag {
+            os: AbiTagOs::Linux,
+            major: 3,
+            minor: 2,
+            subminor: 0
+        })
+    );
+    assert_eq!(hwcaps, [(0, &b"nosegneg"[..])]);
+    let bytes = std::fs::read("tests/data/gold-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = zelf::section::Sections::parse(elf).unwrap().unwrap();
+    let section = sections.by_name(b".note.gnu.gold-version").unwrap();
+    let note = Notes::<Little64>::parse(section.content(), section.header().addralign())
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap();
+    match GnuNote::parse::<Little64>(note).unwrap() {
+        Some(GnuNote::GoldVersion(x)) => assert_eq!(x, b"gold 1.16"),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn gnu_properties() {
+    let bytes = std::fs::read("tests/data/note-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let properties = GnuProperties::find(elf).unwrap();
+    let properties = properties
+        .iter()
+        .map(|x| x.parse(elf.header().machine()).unwrap())
+        .collect::<Vec<_>>();
+    assert!(properties.contains(&GnuProperty::X86Feature1And(
+        X86Feature1::IBT | X86Feature1::SHSTK
+    )));
+    let needed = properties
+        .iter()
+        .find_map(|x| match x {
+            GnuProperty::X86Isa1Needed(x) => Some(*x),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(needed & X86Isa1::V3, X86Isa1::V3);
+    assert_eq!(needed & X86Isa1::V4, X86Isa1(0));
+}
@@ -0,0 +1,85 @@
+use zelf::elf::Elf;
+use zelf::program::{Programs, VaddrContent};
+
+#[test]
+fn iter() {
+    let bytes = std::fs::read("tests/data/dynamic-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let programs = Programs::parse(elf).unwrap().unwrap();
+    assert_eq!(programs.num(), 9);
+    assert_eq!(programs.iter().count(), programs.num() as usize);
+    assert!(programs
+        .iter()
+        .enumerate()
+        .all(|(i, (index, x))| i == index as usize && x.is_ok()));
+}
+
+#[test]
+fn vaddr() {
+    let bytes = std::fs::read("tests/data/dynamic-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let programs = Programs::parse(elf).unwrap().unwrap();
+    // "answer" in ".text"
+    assert_eq!(programs.vaddr_to_offset(0x1120), Some(0x1120));
+    assert_eq!(
+        programs.read_at_vaddr(0x1120, 6),
+        Some(VaddrContent::File(&bytes[0x1120..0x1126]))
+    );
+    // ".init_array"
+    assert_eq!(programs.vaddr_to_offset(0x3dc0), Some(0x2dc0));
+    assert_eq!(programs.offset_to_vaddr(0x2dc0), Some(0x3dc0));
+    match programs.read_at_vaddr(0x3dc0, 16) {
+        Some(VaddrContent::File(x)) => {
+            let init = u64::from_le_bytes(x[8..].try_into().unwrap());
+            assert!((0x1000..0x1131).contains(&init));
+        }
+        _ => unreachable!(),
+    }
+    // ".init_array" to the end of the data load program
+    assert_eq!(
+        programs.content_at_vaddr(0x3dc0),
+        Some(&bytes[0x2dc0..0x3008])
+    );
+    // "initialized" in ".bss"
+    assert_eq!(programs.content_at_vaddr(0x400c), None);
+    assert_eq!(programs.vaddr_to_offset(0x400c), None);
+    assert_eq!(programs.read_at_vaddr(0x400c, 4), Some(VaddrContent::Bss));
+    assert_eq!(programs.read_at_vaddr(0x4004, 8), None);
+    assert_eq!(programs.read_at_vaddr(0x400c, 8), None);
+    // unmapped
+    assert_eq!(programs.vaddr_to_offset(0x5000), None);
+    assert_eq!(programs.offset_to_vaddr(0x3100), None);
+}
+
+#[test]
+fn vaddr_overflow() {
+    let mut bytes = std::fs::read("tests/data/dynamic-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let programs = Programs::parse(elf).unwrap().unwrap();
+    // the data load program, which starts at 0x2dc0 in the file
+    let (index, _) = programs
+        .iter()
+        .find(|(_, x)| x.as_ref().unwrap().header().offset() == 0x2dc0)
+        .unwrap();
+    let phoff = u64::from_le_bytes(bytes[0x20..0x28].try_into().unwrap()) as usize;
+    let vaddr = phoff + 56 * index as usize + 16;
+    bytes[vaddr..vaddr + 8].copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let programs = Programs::parse(elf).unwrap().unwrap();
+    assert_eq!(programs.offset_to_vaddr(0x2dc0), Some(u64::MAX - 0x10));
+    assert_eq!(programs.offset_to_vaddr(0x2dd0), Some(u64::MAX));
+    assert_eq!(programs.offset_to_vaddr(0x2dd1), None);
+    assert_eq!(programs.vaddr_to_offset(u64::MAX), Some(0x2dd0));
+}
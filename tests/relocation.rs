@@ -0,0 +1,414 @@
+use zelf::context::{Context, Little32};
+use zelf::elf::{Elf, Variant, ELF_MACHINE_MIPS};
+use zelf::rel::{Mips64Info, Rel};
+use zelf::rela::Rela;
+use zelf::relocation::{RelocationAarch64, RelocationArm, RelocationI386, RelocationX86_64};
+use zelf::relocation::{RelocationLoongArch, RelocationMips, RelocationType};
+use zelf::relocation::{RelocationPpc64, RelocationRiscv, RelocationS390};
+use zelf::section::{SectionType, Sections};
+
+/// Offsets, symbol indexes, relocation types and addends of the section.
+fn rela<T: Context>(elf: Variant<'_, T>, name: &[u8]) -> Vec<(u64, u32, u32, i64)> {
+    let machine = elf.header().machine();
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let rela = Rela::<T>::parse(sections.by_name(name).unwrap().content()).unwrap();
+    rela.entries()
+        .iter()
+        .map(|x| {
+            let offset: u64 = x.offset().into();
+            (offset, x.sym(machine), x.typa(machine), x.signed_addend())
+        })
+        .collect()
+}
+
+fn rel<T: Context>(elf: Variant<'_, T>, name: &[u8]) -> Vec<(u64, u32, u32)> {
+    let machine = elf.header().machine();
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let rel = Rel::<T>::parse(sections.by_name(name).unwrap().content()).unwrap();
+    rel.entries()
+        .iter()
+        .map(|x| (x.offset().into(), x.sym(machine), x.typa(machine)))
+        .collect()
+}
+
+#[test]
+fn info() {
+    let bytes = std::fs::read("tests/data/relocation-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    assert_eq!(rela(elf, b".rela.text"), [(0x6, 4, 9, -4), (0xd, 5, 4, -4)]);
+    assert_eq!(
+        rela(elf, b".rela.data"),
+        [(0x0, 4, 1, 0), (0x8, 8, 1, -8), (0x10, 8, 1, 8)]
+    );
+    // "r_info" of ELF32 is split by "ELF32_R_SYM" and "ELF32_R_TYPE"
+    let bytes = std::fs::read("tests/data/relocation-i386").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little32(elf) => elf,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        rel(elf, b".rel.text"),
+        [(0xc, 4, 10), (0x12, 5, 3), (0x1c, 6, 4)]
+    );
+    assert_eq!(
+        rel(elf, b".rel.data"),
+        [(0x0, 5, 1), (0x4, 9, 1), (0x8, 9, 1)]
+    );
+    let bytes = std::fs::read("tests/data/relocation-riscv32").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little32(elf) => elf,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        rela(elf, b".rela.text"),
+        [(0xc, 8, 0x14, 0), (0x10, 5, 0x18, 0), (0x20, 9, 0x13, 0)]
+    );
+    // the addend of ELF32 is sign-extended
+    assert_eq!(
+        rela(elf, b".rela.sdata"),
+        [(0x0, 8, 1, 0), (0x4, 12, 1, -8), (0x8, 12, 1, 8)]
+    );
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let sdata =
+        Rela::<Little32>::parse(sections.by_name(b".rela.sdata").unwrap().content()).unwrap();
+    assert_eq!(sdata.entries()[1].addend(), 0xfffffff8);
+}
+
+#[test]
+fn mips64() {
+    fn check<T: Context>(elf: Variant<'_, T>) {
+        assert_eq!(elf.header().machine(), ELF_MACHINE_MIPS);
+        assert_eq!(
+            rela(elf, b".rela.text"),
+            [
+                (0x10, 3, 7, 0),
+                (0x18, 3, 7, 0),
+                (0x20, 4, 0x13, 0),
+                (0x24, 5, 0xb, 0),
+                (0x28, 5, 0x25, 0)
+            ]
+        );
+        assert_eq!(
+            rela(elf, b".rela.data"),
+            [(0x0, 4, 0x12, 0), (0x8, 8, 0x12, -8), (0x10, 8, 0x12, 8)]
+        );
+        let sections = Sections::parse(elf).unwrap().unwrap();
+        let text = Rela::<T>::parse(sections.by_name(b".rela.text").unwrap().content()).unwrap();
+        // "R_MIPS_GPREL16", "R_MIPS_SUB" and "R_MIPS_HI16"
+        assert_eq!(
+            text.entries()[0].mips64(),
+            Mips64Info {
+                sym: 3,
+                ssym: 0,
+                typa3: 5,
+                typa2: 0x18,
+                typa: 7,
+            }
+        );
+        let names = |x: Mips64Info| {
+            [x.typa, x.typa2, x.typa3].map(|x| {
+                RelocationType::parse(ELF_MACHINE_MIPS, x as u32)
+                    .unwrap()
+                    .name()
+            })
+        };
+        assert_eq!(
+            names(text.entries()[1].mips64()),
+            ["R_MIPS_GPREL16", "R_MIPS_SUB", "R_MIPS_LO16"]
+        );
+        assert_eq!(text.entries()[2].mips64().typa2, 0);
+    }
+    let bytes = std::fs::read("tests/data/relocation-mips64el").unwrap();
+    match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => check(elf),
+        _ => unreachable!(),
+    }
+    let bytes = std::fs::read("tests/data/relocation-mips64").unwrap();
+    match Elf::parse(&bytes).unwrap() {
+        Elf::Big64(elf) => check(elf),
+        _ => unreachable!(),
+    }
+}
+
+/// Relocation types of all relocation sections, in the order of "readelf -r".
+fn types<T: Context>(elf: Variant<'_, T>) -> Vec<Option<RelocationType>> {
+    let machine = elf.header().machine();
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let mut types = Vec::new();
+    for (_, section) in sections.iter() {
+        let section = section.unwrap();
+        match section.header().checked_type() {
+            Some(SectionType::Rel) => {
+                let rel = Rel::<T>::parse(section.content()).unwrap();
+                types.extend(rel.entries().iter().map(|x| x.checked_type(machine)));
+            }
+            Some(SectionType::Rela) => {
+                let rela = Rela::<T>::parse(section.content()).unwrap();
+                types.extend(rela.entries().iter().map(|x| x.checked_type(machine)));
+            }
+            _ => (),
+        }
+    }
+    types
+}
+
+fn names<T: Context>(elf: Variant<'_, T>) -> Vec<&'static str> {
+    types(elf).into_iter().map(|x| x.unwrap().name()).collect()
+}
+
+#[test]
+fn relocation_types() {
+    // names are given by GNU readelf
+    let expected: [(&str, &[&str]); 14] = [
+        (
+            "relocation-x86_64",
+            &[
+                "R_X86_64_GOTPCREL",
+                "R_X86_64_PLT32",
+                "R_X86_64_64",
+                "R_X86_64_64",
+                "R_X86_64_64",
+                "R_X86_64_PC32",
+            ],
+        ),
+        (
+            "dynamic-x86_64",
+            &[
+                "R_X86_64_RELATIVE",
+                "R_X86_64_RELATIVE",
+                "R_X86_64_RELATIVE",
+                "R_X86_64_RELATIVE",
+                "R_X86_64_RELATIVE",
+                "R_X86_64_GLOB_DAT",
+                "R_X86_64_GLOB_DAT",
+                "R_X86_64_GLOB_DAT",
+                "R_X86_64_GLOB_DAT",
+                "R_X86_64_GLOB_DAT",
+            ],
+        ),
+        (
+            "relocation-i386",
+            &[
+                "R_386_GOTPC",
+                "R_386_GOT32",
+                "R_386_PLT32",
+                "R_386_32",
+                "R_386_32",
+                "R_386_32",
+                "R_386_PC32",
+            ],
+        ),
+        (
+            "dynamic-i386",
+            &["R_386_RELATIVE", "R_386_RELATIVE", "R_386_GLOB_DAT"],
+        ),
+        (
+            "relocation-aarch64",
+            &[
+                "R_AARCH64_ADR_GOT_PAGE",
+                "R_AARCH64_LD64_GOT_LO12_NC",
+                "R_AARCH64_CALL26",
+                "R_AARCH64_ABS64",
+                "R_AARCH64_ABS64",
+                "R_AARCH64_ABS64",
+                "R_AARCH64_PREL32",
+            ],
+        ),
+        (
+            "relocation-arm",
+            &[
+                "R_ARM_CALL",
+                "R_ARM_GOT_PREL",
+                "R_ARM_NONE",
+                "R_ARM_PREL31",
+                "R_ARM_ABS32",
+                "R_ARM_ABS32",
+                "R_ARM_ABS32",
+            ],
+        ),
+        (
+            "relocation-riscv32",
+            &[
+                "R_RISCV_GOT_HI20",
+                "R_RISCV_PCREL_LO12_I",
+                "R_RISCV_CALL_PLT",
+                "R_RISCV_32",
+                "R_RISCV_32",
+                "R_RISCV_32",
+                "R_RISCV_32_PCREL",
+                "R_RISCV_ADD32",
+                "R_RISCV_SUB32",
+                "R_RISCV_SET6",
+                "R_RISCV_SUB6",
+                "R_RISCV_SET6",
+                "R_RISCV_SUB6",
+            ],
+        ),
+        (
+            "relocation-riscv64",
+            &[
+                "R_RISCV_GOT_HI20",
+                "R_RISCV_PCREL_LO12_I",
+                "R_RISCV_CALL_PLT",
+                "R_RISCV_64",
+                "R_RISCV_64",
+                "R_RISCV_64",
+                "R_RISCV_32_PCREL",
+                "R_RISCV_ADD32",
+                "R_RISCV_SUB32",
+                "R_RISCV_SET6",
+                "R_RISCV_SUB6",
+                "R_RISCV_SET6",
+                "R_RISCV_SUB6",
+            ],
+        ),
+        (
+            "relocation-ppc64le",
+            &[
+                "R_PPC64_REL16_HA",
+                "R_PPC64_REL16_LO",
+                "R_PPC64_TOC16_HA",
+                "R_PPC64_TOC16_LO_DS",
+                "R_PPC64_REL24",
+                "R_PPC64_ADDR64",
+                "R_PPC64_ADDR64",
+                "R_PPC64_ADDR64",
+                "R_PPC64_ADDR64",
+                "R_PPC64_REL32",
+            ],
+        ),
+        (
+            "relocation-mips",
+            &[
+                "R_MIPS_HI16",
+                "R_MIPS_LO16",
+                "R_MIPS_GOT16",
+                "R_MIPS_CALL16",
+                "R_MIPS_JALR",
+                "R_MIPS_32",
+                "R_MIPS_32",
+                "R_MIPS_32",
+                "R_MIPS_32",
+                "R_MIPS_PC32",
+            ],
+        ),
+        (
+            "relocation-mips64el",
+            &[
+                "R_MIPS_GPREL16",
+                "R_MIPS_GPREL16",
+                "R_MIPS_GOT_DISP",
+                "R_MIPS_CALL16",
+                "R_MIPS_JALR",
+                "R_MIPS_32",
+                "R_MIPS_64",
+                "R_MIPS_64",
+                "R_MIPS_64",
+                "R_MIPS_PC32",
+            ],
+        ),
+        (
+            "relocation-mips64",
+            &[
+                "R_MIPS_GPREL16",
+                "R_MIPS_GPREL16",
+                "R_MIPS_GOT_DISP",
+                "R_MIPS_CALL16",
+                "R_MIPS_JALR",
+                "R_MIPS_32",
+                "R_MIPS_64",
+                "R_MIPS_64",
+                "R_MIPS_64",
+                "R_MIPS_PC32",
+            ],
+        ),
+        (
+            "relocation-s390x",
+            &[
+                "R_390_GOTENT",
+                "R_390_PLT32DBL",
+                "R_390_64",
+                "R_390_64",
+                "R_390_64",
+                "R_390_PC32",
+            ],
+        ),
+        (
+            "relocation-loongarch64",
+            &[
+                "R_LARCH_PCALA_HI20",
+                "R_LARCH_PCALA_LO12",
+                "R_LARCH_GOT_PC_HI20",
+                "R_LARCH_GOT_PC_LO12",
+                "R_LARCH_B26",
+                "R_LARCH_RELAX",
+                "R_LARCH_64",
+                "R_LARCH_64",
+            ],
+        ),
+    ];
+    for (file, expected) in expected {
+        let bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+        let names = match Elf::parse(&bytes).unwrap() {
+            Elf::Little32(elf) => names(elf),
+            Elf::Little64(elf) => names(elf),
+            Elf::Big32(elf) => names(elf),
+            Elf::Big64(elf) => names(elf),
+        };
+        assert_eq!(names, expected, "{}", file);
+    }
+}
+
+#[test]
+fn relocation_type() {
+    fn first(file: &str) -> Option<RelocationType> {
+        let bytes = std::fs::read(format!("tests/data/{}", file)).unwrap();
+        match Elf::parse(&bytes).unwrap() {
+            Elf::Little32(elf) => types(elf)[0],
+            Elf::Little64(elf) => types(elf)[0],
+            Elf::Big32(elf) => types(elf)[0],
+            Elf::Big64(elf) => types(elf)[0],
+        }
+    }
+    use RelocationType::*;
+    assert_eq!(
+        first("relocation-x86_64"),
+        Some(X86_64(RelocationX86_64::Gotpcrel))
+    );
+    assert_eq!(first("dynamic-i386"), Some(I386(RelocationI386::Relative)));
+    assert_eq!(
+        first("relocation-aarch64"),
+        Some(Aarch64(RelocationAarch64::AdrGotPage))
+    );
+    assert_eq!(first("relocation-arm"), Some(Arm(RelocationArm::Call)));
+    assert_eq!(
+        first("relocation-riscv64"),
+        Some(Riscv(RelocationRiscv::GotHi20))
+    );
+    assert_eq!(
+        first("relocation-ppc64le"),
+        Some(Ppc64(RelocationPpc64::Rel16Ha))
+    );
+    assert_eq!(first("relocation-mips"), Some(Mips(RelocationMips::Hi16)));
+    assert_eq!(
+        first("relocation-s390x"),
+        Some(S390(RelocationS390::Gotent))
+    );
+    assert_eq!(
+        first("relocation-loongarch64"),
+        Some(LoongArch(RelocationLoongArch::PcalaHi20))
+    );
+    let x = RelocationType::parse(zelf::elf::ELF_MACHINE_X86_64, 6).unwrap();
+    assert_eq!(x, X86_64(RelocationX86_64::GlobDat));
+    assert_eq!(x.name(), "R_X86_64_GLOB_DAT");
+    assert_eq!(u32::from(x), 6);
+    // unknown architectures and types
+    assert_eq!(RelocationType::parse(0xffff, 1), None);
+    assert_eq!(
+        RelocationType::parse(zelf::elf::ELF_MACHINE_X86_64, 0xffff),
+        None
+    );
+}
@@ -0,0 +1,25 @@
+use zelf::elf::Elf;
+use zelf::section::Sections;
+
+#[test]
+fn iter() {
+    let bytes = std::fs::read("tests/data/dynamic-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    assert_eq!(sections.num(), 25);
+    assert_eq!(sections.iter().count(), sections.num() as usize);
+    assert!(sections
+        .iter()
+        .enumerate()
+        .all(|(i, (index, x))| i == index as usize && x.is_ok()));
+    let bytes = std::fs::read("tests/data/dynamic-i386").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little32(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    assert_eq!(sections.iter().count(), sections.num() as usize);
+}
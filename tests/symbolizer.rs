@@ -0,0 +1,168 @@
+use zelf::context::{Little32, Little64};
+use zelf::elf::Elf;
+#[cfg(feature = "minidebuginfo")]
+use zelf::mini_debug_info::MiniDebugInfo;
+use zelf::section::Sections;
+use zelf::symbolizer::{Symbolizer, SymbolizerEntry};
+use zelf::symbols::Symbols;
+
+fn little64(bytes: &[u8]) -> zelf::elf::Variant<'_, Little64> {
+    match Elf::parse(bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    }
+}
+
+fn little32(bytes: &[u8]) -> zelf::elf::Variant<'_, Little32> {
+    match Elf::parse(bytes).unwrap() {
+        Elf::Little32(elf) => elf,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn symbolizer() {
+    let bytes = std::fs::read("tests/data/debug-x86_64").unwrap();
+    let mut symbolizer = Symbolizer::parse(little64(&bytes)).unwrap();
+    let entry = |name: &'static str, address, size| SymbolizerEntry {
+        name: name.as_bytes(),
+        address,
+        size,
+    };
+    assert_eq!(
+        symbolizer.entries(),
+        [
+            entry("add", 0x1000, 4),
+            entry("sub", 0x1010, 5),
+            entry("mul", 0x1020, 6),
+            entry("increase", 0x1030, 15),
+            entry("call_hidden", 0x1040, 5),
+            // no sizes, so they end at the end of ".dynamic" and ".got.plt"
+            entry("_DYNAMIC", 0x3f00, 0xe0),
+            entry("_GLOBAL_OFFSET_TABLE_", 0x3fe8, 0x18),
+            entry("message", 0x4000, 8),
+            entry("counter", 0x4008, 4),
+        ]
+    );
+    assert_eq!(symbolizer.lookup(0xfff), None);
+    assert_eq!(symbolizer.lookup(0x1000), Some((&b"add"[..], 0)));
+    assert_eq!(symbolizer.lookup(0x1003), Some((&b"add"[..], 3)));
+    assert_eq!(symbolizer.lookup(0x1004), None);
+    assert_eq!(symbolizer.lookup(0x4009), Some((&b"counter"[..], 1)));
+    assert_eq!(symbolizer.lookup(0x400c), None);
+    // load bias of a position-independent file
+    symbolizer.set_bias(0x7f0000000000);
+    assert_eq!(symbolizer.lookup(0x1003), None);
+    assert_eq!(symbolizer.lookup(0x7f0000001012), Some((&b"sub"[..], 2)));
+}
+
+#[test]
+fn symbolizer_gap() {
+    let bytes = std::fs::read("tests/data/gnu-i386").unwrap();
+    let elf = little32(&bytes);
+    let symbolizer = Symbolizer::parse(elf).unwrap();
+    // "__x86.get_pc_thunk.ax" has no size, so it ends at the end of ".text"
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let text = sections.by_name(b".text").unwrap().header();
+    let end = u64::from(text.addr()) + u64::from(text.size());
+    let thunk = symbolizer
+        .entries()
+        .iter()
+        .find(|x| x.name == b"__x86.get_pc_thunk.ax")
+        .unwrap();
+    assert_eq!(thunk.address + thunk.size, end);
+    assert_eq!(
+        symbolizer.lookup(end - 1),
+        Some((&b"__x86.get_pc_thunk.ax"[..], end - 1 - thunk.address))
+    );
+    assert_eq!(symbolizer.lookup(end), None);
+}
+
+#[test]
+fn symbolizer_thumb() {
+    let mut bytes = std::fs::read("tests/data/gnu-i386").unwrap();
+    let offset = {
+        let elf = little32(&bytes);
+        let sections = Sections::parse(elf).unwrap().unwrap();
+        let symtab = sections.by_name(b".symtab").unwrap();
+        let symbols = Symbols::parse(sections, symtab).unwrap();
+        let add = symbols.iter().find(|x| x.name() == Some(b"add")).unwrap();
+        add.entry() as *const _ as usize - bytes.as_ptr() as usize
+    };
+    // pretend to be ARM, and "add" to be Thumb code
+    bytes[0x12..0x14].copy_from_slice(&40u16.to_le_bytes());
+    bytes[offset + 4..offset + 8].copy_from_slice(&0x1001u32.to_le_bytes());
+    let symbolizer = Symbolizer::parse(little32(&bytes)).unwrap();
+    let add = symbolizer.entries()[0];
+    assert_eq!((add.name, add.address, add.size), (&b"add"[..], 0x1000, 9));
+    assert_eq!(symbolizer.lookup(0x1003), Some((&b"add"[..], 3)));
+}
+
+#[test]
+fn symbolizer_nested() {
+    let mut bytes = std::fs::read("tests/data/debug-x86_64").unwrap();
+    let offset = {
+        let elf = little64(&bytes);
+        let sections = Sections::parse(elf).unwrap().unwrap();
+        let symtab = sections.by_name(b".symtab").unwrap();
+        let symbols = Symbols::parse(sections, symtab).unwrap();
+        let add = symbols.iter().find(|x| x.name() == Some(b"add")).unwrap();
+        add.entry() as *const _ as usize - bytes.as_ptr() as usize
+    };
+    // pretend "add" to contain "sub" and "mul"
+    bytes[offset + 16..offset + 24].copy_from_slice(&0x30u64.to_le_bytes());
+    let symbolizer = Symbolizer::parse(little64(&bytes)).unwrap();
+    assert_eq!(symbolizer.lookup(0x1012), Some((&b"sub"[..], 2)));
+    assert_eq!(symbolizer.lookup(0x1016), Some((&b"add"[..], 0x16)));
+    assert_eq!(symbolizer.lookup(0x1025), Some((&b"mul"[..], 5)));
+    assert_eq!(symbolizer.lookup(0x102f), Some((&b"add"[..], 0x2f)));
+    assert_eq!(symbolizer.lookup(0x1030), Some((&b"increase"[..], 0)));
+    assert_eq!(symbolizer.lookup(0x103f), None);
+}
+
+#[test]
+#[cfg(feature = "minidebuginfo")]
+fn symbolizer_merged() {
+    let bytes = std::fs::read("tests/data/minidebuginfo-x86_64").unwrap();
+    let elf = little64(&bytes);
+    // only ".dynsym"
+    let symbolizer = Symbolizer::parse(elf).unwrap();
+    assert!(symbolizer.entries().iter().all(|x| x.name != b"hidden"));
+    let mini = MiniDebugInfo::find(elf, 1 << 20).unwrap().unwrap();
+    let nested = Sections::parse(mini.variant::<Little64>().unwrap())
+        .unwrap()
+        .unwrap();
+    let symtab = Symbols::parse(nested, nested.by_name(b".symtab").unwrap()).unwrap();
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let dynsym = Symbols::parse(sections, sections.by_name(b".dynsym").unwrap()).unwrap();
+    let symbolizer = Symbolizer::new(elf, Some(sections), dynsym.iter().chain(symtab.iter()));
+    let hidden = symbolizer
+        .entries()
+        .iter()
+        .find(|x| x.name == b"hidden")
+        .unwrap();
+    assert_eq!(
+        symbolizer.lookup(hidden.address + 1),
+        Some((&b"hidden"[..], 1))
+    );
+}
+
+#[test]
+fn symbolizer_stripped() {
+    let bytes = std::fs::read("tests/data/debug-x86_64").unwrap();
+    let expected = Symbolizer::parse(little64(&bytes)).unwrap();
+    // without section headers
+    let mut stripped = bytes.clone();
+    stripped[0x28..0x30].fill(0);
+    stripped[0x3c..0x40].fill(0);
+    let symbolizer = Symbolizer::parse(little64(&stripped)).unwrap();
+    let functions = |x: &Symbolizer| {
+        x.entries()
+            .iter()
+            .filter(|x| x.address < 0x2000)
+            .map(|x| (x.name.to_vec(), x.address, x.size))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(functions(&symbolizer), functions(&expected));
+    assert_eq!(symbolizer.lookup(0x1041), Some((&b"call_hidden"[..], 1)));
+}
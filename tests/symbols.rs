@@ -0,0 +1,145 @@
+use zelf::context::Little64;
+use zelf::elf::Elf;
+use zelf::section::Sections;
+use zelf::symbols::{ParseSymbolsError, Symbols};
+use zelf::symtab::SymbolSection;
+
+/// Builds a relocatable file with ".symtab", ".strtab", ".shstrtab" and ".symtab_shndx" of "shndx" entries if it's not "None".
+///
+/// Symbol "far" is in section "70000" by "SHN_XINDEX", and symbol "near" is in section "1".
+fn synthesize(shndx: Option<&[u32]>) -> Vec<u8> {
+    fn symbol(name: u32, shndx: u16) -> Vec<u8> {
+        let mut x = Vec::new();
+        x.extend(name.to_le_bytes());
+        // "STB_GLOBAL" and "STT_OBJECT"
+        x.extend([0x11, 0]);
+        x.extend(shndx.to_le_bytes());
+        x.extend([0u8; 16]);
+        x
+    }
+    let strtab = b"\0far\0near\0".to_vec();
+    let symtab = [symbol(0, 0), symbol(1, 0xffff), symbol(5, 1)].concat();
+    let shstrtab = b"\0.strtab\0.symtab\0.shstrtab\0.symtab_shndx\0".to_vec();
+    // name, type, link, content
+    let mut sections = vec![
+        (1u32, 3u32, 0u32, strtab),
+        (9, 2, 1, symtab),
+        (17, 3, 0, shstrtab),
+    ];
+    if let Some(shndx) = shndx {
+        let content = shndx.iter().flat_map(|x| x.to_le_bytes()).collect();
+        sections.push((27, 18, 2, content));
+    }
+    let mut data = vec![0u8; 64];
+    let mut headers = vec![0u8; 64];
+    for (name, typa, link, content) in sections {
+        let offset = data.len() as u64;
+        let entsize: u64 = match typa {
+            2 => 24,
+            18 => 4,
+            _ => 0,
+        };
+        data.extend(&content);
+        while !data.len().is_multiple_of(8) {
+            data.push(0);
+        }
+        headers.extend(name.to_le_bytes());
+        headers.extend(typa.to_le_bytes());
+        headers.extend(0u64.to_le_bytes());
+        headers.extend(0u64.to_le_bytes());
+        headers.extend(offset.to_le_bytes());
+        headers.extend((content.len() as u64).to_le_bytes());
+        headers.extend(link.to_le_bytes());
+        // "sh_info" of ".symtab" is one greater than the index of the last local symbol
+        headers.extend(u32::from(typa == 2).to_le_bytes());
+        headers.extend(8u64.to_le_bytes());
+        headers.extend(entsize.to_le_bytes());
+    }
+    let shoff = data.len() as u64;
+    let shnum = (headers.len() / 64) as u16;
+    data.extend(headers);
+    data[..16].copy_from_slice(b"\x7fELF\x02\x01\x01\0\0\0\0\0\0\0\0\0");
+    // "ET_REL" and "EM_X86_64"
+    data[16..18].copy_from_slice(&1u16.to_le_bytes());
+    data[18..20].copy_from_slice(&62u16.to_le_bytes());
+    data[20..24].copy_from_slice(&1u32.to_le_bytes());
+    data[40..48].copy_from_slice(&shoff.to_le_bytes());
+    data[52..54].copy_from_slice(&64u16.to_le_bytes());
+    data[58..60].copy_from_slice(&64u16.to_le_bytes());
+    data[60..62].copy_from_slice(&shnum.to_le_bytes());
+    data[62..64].copy_from_slice(&3u16.to_le_bytes());
+    data
+}
+
+fn parse(data: &[u8]) -> Result<Symbols<'_, Little64>, ParseSymbolsError> {
+    let elf = match Elf::parse(data).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    Symbols::parse(sections, sections.by_name(b".symtab").unwrap())
+}
+
+#[test]
+fn xindex() {
+    let data = synthesize(Some(&[0, 70000, 0]));
+    let symbols = parse(&data).unwrap();
+    assert!(symbols.shndx().is_some());
+    let resolved = symbols
+        .iter()
+        .map(|x| (x.name().unwrap(), x.entry().section(), x.section()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        resolved,
+        [
+            (
+                &b"far"[..],
+                SymbolSection::XIndex,
+                SymbolSection::Index(70000)
+            ),
+            (b"near", SymbolSection::Index(1), SymbolSection::Index(1)),
+        ]
+    );
+    // the table is shorter than the symbol table
+    let data = synthesize(Some(&[0, 70000]));
+    assert!(matches!(parse(&data), Err(ParseSymbolsError::BrokenShndx)));
+    // there is no table
+    let data = synthesize(None);
+    assert!(matches!(parse(&data), Err(ParseSymbolsError::MissingShndx)));
+}
+
+#[test]
+fn symbols() {
+    let bytes = std::fs::read("tests/data/symbol-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let symbols = Symbols::parse(sections, sections.by_name(b".symtab").unwrap()).unwrap();
+    assert!(symbols.shndx().is_none());
+    assert_eq!(symbols.num(), 15);
+    assert_eq!(symbols.iter().count(), 14);
+    let locals = symbols
+        .locals()
+        .map(|x| x.name().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        locals,
+        [
+            &b"symbol.c"[..],
+            b"",
+            b"local",
+            b"implementation",
+            b"resolve"
+        ]
+    );
+    assert_eq!(symbols.globals().count(), 9);
+    assert!(symbols.iter().all(|x| x.section() == x.entry().section()));
+    // only symtab and dynsym sections are accepted
+    let strtab = sections.by_name(b".strtab").unwrap();
+    assert!(matches!(
+        Symbols::parse(sections, strtab),
+        Err(ParseSymbolsError::BadPropertyType)
+    ));
+}
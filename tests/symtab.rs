@@ -0,0 +1,131 @@
+use zelf::context::Little64;
+use zelf::elf::Elf;
+use zelf::section::Sections;
+use zelf::strtab::Strtab;
+use zelf::symtab::{SymbolBinding, SymbolSection, SymbolType, SymbolVisibility, Symtab};
+use zelf::symtab::{SYMBOL_BINDING_GNU_UNIQUE, SYMBOL_TYPE_GNU_IFUNC};
+
+#[test]
+fn symtab() {
+    let bytes = std::fs::read("tests/data/symbol-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let section = sections.by_name(b".symtab").unwrap();
+    let symtab = Symtab::<Little64>::parse(section.content()).unwrap();
+    let strtab = Strtab::parse(sections.by_name(b".strtab").unwrap().content()).unwrap();
+    let find = |name: &[u8]| {
+        symtab
+            .iter()
+            .find(|(_, x)| strtab.find(x.name() as usize) == Some(name))
+            .unwrap()
+            .1
+    };
+    let text = match find(b"global").section() {
+        SymbolSection::Index(x) => x,
+        _ => unreachable!(),
+    };
+    let expected = [
+        (
+            &b"symbol.c"[..],
+            SymbolBinding::Local,
+            SymbolType::File,
+            SymbolVisibility::Default,
+            SymbolSection::Abs,
+        ),
+        (
+            b"local",
+            SymbolBinding::Local,
+            SymbolType::Func,
+            SymbolVisibility::Default,
+            SymbolSection::Index(text),
+        ),
+        (
+            b"global",
+            SymbolBinding::Global,
+            SymbolType::Func,
+            SymbolVisibility::Default,
+            SymbolSection::Index(text),
+        ),
+        (
+            b"external",
+            SymbolBinding::Global,
+            SymbolType::NoType,
+            SymbolVisibility::Default,
+            SymbolSection::Undef,
+        ),
+        (
+            b"weak",
+            SymbolBinding::Weak,
+            SymbolType::Func,
+            SymbolVisibility::Default,
+            SymbolSection::Index(text),
+        ),
+        (
+            b"indirect",
+            SymbolBinding::Global,
+            SYMBOL_TYPE_GNU_IFUNC,
+            SymbolVisibility::Default,
+            SymbolSection::Index(text),
+        ),
+        (
+            b"hidden",
+            SymbolBinding::Global,
+            SymbolType::Func,
+            SymbolVisibility::Hidden,
+            SymbolSection::Index(text),
+        ),
+        (
+            b"protected",
+            SymbolBinding::Global,
+            SymbolType::Func,
+            SymbolVisibility::Protected,
+            SymbolSection::Index(text),
+        ),
+        (
+            b"tls",
+            SymbolBinding::Global,
+            SymbolType::Tls,
+            SymbolVisibility::Default,
+            SymbolSection::Index(sections.by_name(b".tbss").unwrap().index() as u32),
+        ),
+        (
+            b"common",
+            SymbolBinding::Global,
+            SymbolType::Object,
+            SymbolVisibility::Default,
+            SymbolSection::Common,
+        ),
+        (
+            b"unique",
+            SYMBOL_BINDING_GNU_UNIQUE,
+            SymbolType::Object,
+            SymbolVisibility::Default,
+            SymbolSection::Index(sections.by_name(b".data.unique").unwrap().index() as u32),
+        ),
+    ];
+    for (name, binding, typa, visibility, section) in expected {
+        let entry = find(name);
+        assert_eq!(entry.checked_binding(), Some(binding));
+        assert_eq!(entry.checked_type(), Some(typa));
+        assert_eq!(entry.visibility(), visibility);
+        assert_eq!(entry.section(), section);
+    }
+    let section = symtab.entries()[2].clone();
+    assert_eq!(section.typa(), SymbolType::Section);
+    assert_eq!(section.section(), SymbolSection::Index(text));
+    // "sh_info" is one greater than the index of the last local symbol
+    let info = sections.by_name(b".symtab").unwrap().header().info();
+    assert!(symtab
+        .locals(info)
+        .all(|(_, x)| x.binding() == SymbolBinding::Local));
+    assert!(symtab
+        .globals(info)
+        .all(|(_, x)| x.binding() != SymbolBinding::Local));
+    assert_eq!(
+        symtab.locals(info).count() + symtab.globals(info).count(),
+        14
+    );
+}
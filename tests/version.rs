@@ -0,0 +1,74 @@
+use zelf::context::Little64;
+use zelf::elf::Elf;
+use zelf::gnu_hash::GnuHash;
+use zelf::hash::Hash;
+use zelf::section::Sections;
+use zelf::strtab::Strtab;
+use zelf::symtab::Symtab;
+use zelf::version::{SymbolVersion, Versions};
+
+#[test]
+fn version() {
+    let bytes = std::fs::read("tests/data/version-x86_64").unwrap();
+    let elf = match Elf::parse(&bytes).unwrap() {
+        Elf::Little64(elf) => elf,
+        _ => unreachable!(),
+    };
+    let sections = Sections::parse(elf).unwrap().unwrap();
+    let versions = Versions::<Little64>::parse(sections).unwrap().unwrap();
+    let symtab =
+        Symtab::<Little64>::parse(sections.by_name(b".dynsym").unwrap().content()).unwrap();
+    let strtab = Strtab::parse(sections.by_name(b".dynstr").unwrap().content()).unwrap();
+    let definitions = versions.verdef().unwrap();
+    assert_eq!(definitions.num(), 3);
+    let names = definitions
+        .iter()
+        .map(|x| strtab.find(x.name().unwrap() as usize).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, [&b"version-x86_64"[..], b"VERS_1", b"VERS_2"]);
+    let requirements = versions.verneed().unwrap();
+    assert_eq!(requirements.num(), 1);
+    let mut found = Vec::new();
+    for (index, entry) in symtab.iter() {
+        let name = strtab.find(entry.name() as usize).unwrap();
+        let version = versions.get(index).unwrap();
+        let formatted = format!(
+            "{}{}{}",
+            String::from_utf8_lossy(name),
+            version.separator(),
+            String::from_utf8_lossy(version.name().unwrap_or_default())
+        );
+        found.push(formatted);
+        if name == b"puts" {
+            assert_eq!(
+                version,
+                SymbolVersion::Needed {
+                    name: b"GLIBC_2.2.5",
+                    file: b"libc.so.6",
+                    hidden: false
+                }
+            );
+        }
+    }
+    assert!(found.contains(&"puts@GLIBC_2.2.5".to_string()));
+    assert!(found.contains(&"greet@@VERS_1".to_string()));
+    assert!(found.contains(&"answer@VERS_1".to_string()));
+    assert!(found.contains(&"answer@@VERS_2".to_string()));
+    let hash = Hash::<Little64>::parse(sections.by_name(b".hash").unwrap().content()).unwrap();
+    let gnu_hash =
+        GnuHash::<Little64>::parse(sections.by_name(b".gnu.hash").unwrap().content()).unwrap();
+    for version in [None, Some(&b"VERS_1"[..]), Some(b"VERS_2")] {
+        let x = hash
+            .lookup_versioned(b"answer", version, symtab, strtab, versions)
+            .unwrap();
+        let y = gnu_hash
+            .lookup_versioned(b"answer", version, symtab, strtab, versions)
+            .unwrap();
+        assert_eq!(x.0, y.0);
+        let expected = version.unwrap_or(b"VERS_2");
+        assert_eq!(versions.get(x.0).unwrap().name(), Some(expected));
+    }
+    assert!(hash
+        .lookup_versioned(b"answer", Some(b"VERS_3"), symtab, strtab, versions)
+        .is_none());
+}
@@ -1,49 +1,95 @@
-use flate2::read::GzDecoder;
-use std::io::Read;
-use std::ops::{BitAnd, Deref};
+use std::borrow::Cow;
+use std::ops::BitAnd;
 use zelf::context::Context;
 use zelf::elf::Variant;
-use zelf::program::{Program, Programs};
-use zelf::section::{Section, SectionFlags32, Sections, Shstrtab};
+use zelf::program::Programs;
+use zelf::section::{Section, Sections, Shstrtab};
 
-pub fn decompress<'a, T: Context>(section: Section<'a, T>) -> impl Deref<Target = [u8]> + 'a
-where
-    <T as zelf::context::Context>::Integer: BitAnd<Output = T::Integer>,
-{
-    enum Return<'a> {
-        Borrowed(&'a [u8]),
-        Owned(Vec<u8>),
+fn show_note<T: Context>(note: zelf::note::Note, machine: u16) {
+    use zelf::gnu_note::GnuNote::{self, *};
+    let name = core::str::from_utf8(note.name()).unwrap_or("<Invaild UTF-8 String>");
+    match GnuNote::parse::<T>(note) {
+        Ok(Some(AbiTag(x))) => println!(
+            "    [note: {}, ABI tag: {:?} {}.{}.{}]",
+            name, x.os, x.major, x.minor, x.subminor
+        ),
+        Ok(Some(Hwcap(x))) => {
+            let names = x
+                .iter()
+                .map(|(_, name)| String::from_utf8_lossy(name))
+                .collect::<Vec<_>>();
+            println!("    [note: {}, hwcap: {:#x} {:?}]", name, x.mask(), names)
+        }
+        Ok(Some(BuildId(x))) => println!("    [note: {}, build ID: {}]", name, x),
+        Ok(Some(GoldVersion(x))) => println!(
+            "    [note: {}, gold version: {}]",
+            name,
+            String::from_utf8_lossy(x)
+        ),
+        Ok(Some(PropertyType0(x))) => match zelf::gnu_property::GnuProperties::<T>::parse(x) {
+            Ok(properties) => {
+                for property in properties.iter() {
+                    match property.parse(machine) {
+                        Ok(x) => println!("    [note: {}, property: {:x?}]", name, x),
+                        Err(e) => println!("    [note: {}, property: <Error: {:?}>]", name, e),
+                    }
+                }
+            }
+            Err(e) => println!("    [note: {}, properties: <Error: {:?}>]", name, e),
+        },
+        Ok(None) => show_core_note::<T>(note, machine),
+        _ => println!("    [note: {}, {:?}]", name, note.descriptor()),
     }
-    impl Deref for Return<'_> {
-        type Target = [u8];
+}
 
-        fn deref(&self) -> &Self::Target {
-            use Return::*;
-            match self {
-                Borrowed(r) => *r,
-                Owned(v) => v,
+fn show_core_note<T: Context>(note: zelf::note::Note, machine: u16) {
+    use zelf::core_note::CoreNote::{self, *};
+    let name = core::str::from_utf8(note.name()).unwrap_or("<Invaild UTF-8 String>");
+    match CoreNote::<T>::parse(note) {
+        Ok(Some(Prstatus(x))) => println!(
+            "    [note: {}, prstatus: pid {}, signal {}, pc {:x?}]",
+            name,
+            x.header().pid(),
+            x.header().cursig(),
+            x.registers(machine).pc()
+        ),
+        Ok(Some(Prpsinfo(x))) => println!(
+            "    [note: {}, prpsinfo: pid {}, {}]",
+            name,
+            x.pid,
+            String::from_utf8_lossy(x.psargs)
+        ),
+        Ok(Some(File(x))) => {
+            for file in x.iter() {
+                println!(
+                    "    [note: {}, file: {:#x}-{:#x} {:#x} {}]",
+                    name,
+                    file.start(),
+                    file.end(),
+                    file.offset(),
+                    String::from_utf8_lossy(file.name())
+                );
             }
         }
+        _ => println!("    [note: {}, {:?}]", name, note.descriptor()),
     }
-    use zelf::compression::Compression;
-    use zelf::compression::CompressionType::*;
-    let empty = Into::<T::Integer>::into(0);
-    let compressed = Into::<T::Integer>::into(SectionFlags32::COMPRESSED.0);
-    let flags = section.header().flags().into();
-    if flags & compressed != empty {
-        let compression = Compression::<T>::parse(section.content()).unwrap();
-        match compression.header().typa() {
-            Zlib => Return::Owned(
-                GzDecoder::new(compression.content())
-                    .bytes()
-                    .map(Result::unwrap)
-                    .collect(),
-            ),
-            _ => panic!("Unknown compression algorithm."),
-        }
-    } else {
-        Return::Borrowed(section.content())
+}
+
+#[cfg(feature = "alloc")]
+fn content<'a, T: Context>(section: &Section<'a, T>) -> Option<Cow<'a, [u8]>> {
+    Some(section.decompressed().unwrap())
+}
+
+// compressed sections are skipped without decompression
+#[cfg(not(feature = "alloc"))]
+fn content<'a, T: Context>(section: &Section<'a, T>) -> Option<Cow<'a, [u8]>> {
+    use zelf::section::SectionFlags32;
+    let flags: T::Integer = section.header().flags().into();
+    let flags: u64 = flags.into();
+    if flags & u64::from(SectionFlags32::COMPRESSED.0) != 0 {
+        return None;
     }
+    Some(Cow::Borrowed(section.content()))
 }
 
 fn format(s: &str, width: usize) -> String {
@@ -95,9 +141,15 @@ where
         println!("Section Headers:");
         println!("  [No]  Name              Type              Address           Align");
         println!("        Size              EntSize           Flags  Link  Info");
-        for i in 0..sections.num() {
+        for (i, section) in sections.iter() {
             use zelf::section::SectionType::*;
-            let section = Section::parse(sections, i).unwrap().unwrap();
+            let section = match section {
+                Ok(section) => section,
+                Err(e) => {
+                    println!("  [{:2}]  <Error: {:?}>", i, e);
+                    continue;
+                }
+            };
             let name = shstrtab
                 .strtab()
                 .find(section.header().name() as usize)
@@ -118,40 +170,45 @@ where
             print!("  {:4x}", section.header().link());
             print!("  {:4x}", section.header().info());
             println!();
+            let content = match content(&section) {
+                Some(content) => content,
+                None => continue,
+            };
             match section.header().typa() {
                 Symtab | Dynsym => {
-                    zelf::symtab::Symtab::<T>::parse(&decompress(section)).unwrap();
+                    zelf::symtab::Symtab::<T>::parse(&content).unwrap();
                 }
                 Strtab => {
-                    zelf::strtab::Strtab::parse(&decompress(section)).unwrap();
+                    zelf::strtab::Strtab::parse(&content).unwrap();
                 }
                 Rela => {
-                    zelf::rela::Rela::<T>::parse(&decompress(section)).unwrap();
+                    zelf::rela::Rela::<T>::parse(&content).unwrap();
                 }
                 Hash => {
-                    zelf::hash::Hash::<T>::parse(&decompress(section)).unwrap();
+                    zelf::hash::Hash::<T>::parse(&content).unwrap();
                 }
                 Dynamic => {
-                    zelf::dynamic::Dynamic::<T>::parse(&decompress(section)).unwrap();
+                    zelf::dynamic::Dynamic::<T>::parse(&content).unwrap();
                 }
                 Note => {
-                    let content = decompress(section);
-                    let note = zelf::note::Note::parse::<T>(&content).unwrap();
-                    let name =
-                        core::str::from_utf8(note.name()).unwrap_or("<Invaild UTF-8 String>");
-                    println!("    [note: {}, {:?}]", name, note.descriptor());
+                    let notes =
+                        zelf::note::Notes::<T>::parse(&content, section.header().addralign())
+                            .unwrap();
+                    for note in notes.iter() {
+                        show_note::<T>(note, elf.header().machine());
+                    }
                 }
                 Rel => {
-                    zelf::rel::Rel::<T>::parse(&decompress(section)).unwrap();
+                    zelf::rel::Rel::<T>::parse(&content).unwrap();
                 }
                 InitArray | FiniArray | PreinitArray => {
-                    zelf::array::Array::<T>::parse(&decompress(section)).unwrap();
+                    zelf::array::Array::<T>::parse(&content).unwrap();
                 }
                 Group => {
-                    zelf::group::Group::<T>::parse(&decompress(section)).unwrap();
+                    zelf::group::Group::<T>::parse(&content).unwrap();
                 }
                 SymtabShndx => {
-                    zelf::shndx::Shndx::<T>::parse(&decompress(section)).unwrap();
+                    zelf::shndx::Shndx::<T>::parse(&content).unwrap();
                 }
                 _ => (),
             }
@@ -162,9 +219,15 @@ where
         println!("Program Headers:");
         println!("  Type        VirtAddr          PhysAddr          Align");
         println!("              FileSiz           MemSiz            Flags");
-        for i in 0..programs.num() {
+        for (_, program) in programs.iter() {
             use zelf::program::ProgramType::*;
-            let program = Program::parse(programs, i).unwrap().unwrap();
+            let program = match program {
+                Ok(program) => program,
+                Err(e) => {
+                    println!("  <Error: {:?}>", e);
+                    continue;
+                }
+            };
             let typa = format!("{:?}", program.header().typa());
             print!("  {}", format(&typa, 10));
             print!("  {:016x}", program.header().vaddr());
@@ -186,10 +249,12 @@ where
                     zelf::dynamic::Dynamic::<T>::parse(program.content()).unwrap();
                 }
                 Note => {
-                    let note = zelf::note::Note::parse::<T>(program.content()).unwrap();
-                    let name =
-                        core::str::from_utf8(note.name()).unwrap_or("<Invaild UTF-8 String>");
-                    println!("    [note: {}, {:?}]", name, note.descriptor());
+                    let notes =
+                        zelf::note::Notes::<T>::parse(program.content(), program.header().align())
+                            .unwrap();
+                    for note in notes.iter() {
+                        show_note::<T>(note, elf.header().machine());
+                    }
                 }
                 _ => (),
             }